@@ -1,14 +1,5 @@
-use std::io::{self, stdin};
+use boj::{problems::p10951::Solution, solver::Solver};
 
-fn main() -> io::Result<()> {
-    for line in stdin().lines() {
-        let submit = line?
-            .split(' ')
-            .map(|e| e.parse::<i32>().expect("cannot parse!"))
-            .reduce(|acc, e| acc + e)
-            .expect("reduce failed");
-        println!("{}", submit);
-    }
-
-    Ok(())
+fn main() -> boj::solver::Result {
+    Solution::main()
 }
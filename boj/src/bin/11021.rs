@@ -1,21 +1,5 @@
-use std::io::{self, stdin};
-fn main() -> io::Result<()> {
-    let t: i32;
-    let mut line = String::new();
-    stdin().read_line(&mut line)?;
-    t = line.trim().parse::<i32>().expect("parse error!");
+use boj::{problems::p11021::Solution, solver::Solver};
 
-    let mut itr = stdin().lines();
-    for tc in 1..=t {
-        let submit = itr
-            .next()
-            .unwrap()?
-            .split(' ')
-            .map(|e| e.parse::<i32>().expect("parse error!"))
-            .reduce(|acc, e| acc + e)
-            .unwrap_or_default();
-        println!("Case #{}: {}", tc, submit);
-    }
-
-    Ok(())
+fn main() -> boj::solver::Result {
+    Solution::main()
 }
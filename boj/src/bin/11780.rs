@@ -0,0 +1,5 @@
+use boj::{problems::p11780::Solution, solver::Solver};
+
+fn main() -> boj::solver::Result {
+    Solution::main()
+}
@@ -0,0 +1,38 @@
+//! 문제 번호로 풀이를 골라 실행한다.
+//!
+//! ```text
+//! cargo run -p boj --bin boj -- run 11660 < input.txt
+//! cargo run -p boj --bin boj -- list
+//! ```
+
+use std::{env, io, process::ExitCode};
+
+use boj::problems::{self, PROBLEMS};
+
+const USAGE: &str = "usage: boj run <problem>\n       boj list";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    match args.iter().map(String::as_str).collect::<Vec<_>>()[..] {
+        ["run", problem] => {
+            let Some(solve) = problems::find(problem) else {
+                eprintln!("unknown problem: {problem} (try `boj list`)");
+                return ExitCode::FAILURE;
+            };
+            let result = solve(&mut io::stdin().lock(), &mut io::stdout().lock());
+            if let Err(err) = result {
+                eprintln!("Error: {err}");
+                return ExitCode::FAILURE;
+            }
+            ExitCode::SUCCESS
+        }
+        ["list"] => {
+            PROBLEMS.iter().for_each(|(id, _)| println!("{id}"));
+            ExitCode::SUCCESS
+        }
+        _ => {
+            eprintln!("{USAGE}");
+            ExitCode::from(2)
+        }
+    }
+}
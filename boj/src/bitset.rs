@@ -0,0 +1,372 @@
+//! 크기를 실행 중에 정하는 비트 집합.
+//!
+//! `Vec<bool>`보다 8배 작고, 개수 세기나 집합 연산을 64비트씩 한 번에 한다.
+//! 길이 밖의 비트는 항상 0으로 유지한다.
+//!
+//! ```
+//! use boj::bitset::BitSet;
+//!
+//! let mut a: BitSet = [1, 3, 4].into_iter().collect();
+//! assert_eq!(5, a.len());
+//! assert_eq!("01011", format!("{a:?}"));
+//!
+//! a <<= 1; // 비트 i가 i + 1로 간다. 길이를 넘는 비트는 버린다.
+//! assert_eq!(vec![2, 4], a.iter_ones().collect::<Vec<_>>());
+//! assert_eq!(Some(4), a.first_set_after(2));
+//! ```
+
+use std::{
+    fmt,
+    iter::FromIterator,
+    ops::{Shl, ShlAssign, Shr, ShrAssign},
+};
+
+const BITS: usize = u64::BITS as usize;
+
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct BitSet {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl BitSet {
+    /// 모두 0인 `len`비트
+    pub fn new(len: usize) -> Self {
+        Self {
+            words: vec![0; len.div_ceil(BITS)],
+            len,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn check(&self, idx: usize) {
+        assert!(
+            idx < self.len,
+            "index {idx} out of range for BitSet of length {}",
+            self.len
+        );
+    }
+
+    pub fn get(&self, idx: usize) -> bool {
+        self.check(idx);
+        self.words[idx / BITS] >> (idx % BITS) & 1 == 1
+    }
+
+    pub fn set(&mut self, idx: usize) {
+        self.check(idx);
+        self.words[idx / BITS] |= 1 << (idx % BITS);
+    }
+
+    pub fn reset(&mut self, idx: usize) {
+        self.check(idx);
+        self.words[idx / BITS] &= !(1 << (idx % BITS));
+    }
+
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// 1인 비트의 번호를 작은 것부터
+    pub fn iter_ones(&self) -> IterOnes<'_> {
+        IterOnes {
+            words: &self.words,
+            word: self.words.first().copied().unwrap_or(0),
+            base: 0,
+        }
+    }
+
+    /// `idx`보다 큰 번호 중 1인 첫 비트
+    pub fn first_set_after(&self, idx: usize) -> Option<usize> {
+        let start = idx.checked_add(1)?;
+        if start >= self.len {
+            return None;
+        }
+        let mut w = start / BITS;
+        let mut word = self.words[w] & (!0 << (start % BITS));
+        loop {
+            if word != 0 {
+                return Some(w * BITS + word.trailing_zeros() as usize);
+            }
+            w += 1;
+            word = *self.words.get(w)?;
+        }
+    }
+
+    fn zip_with(&mut self, other: &BitSet, f: impl Fn(u64, u64) -> u64) {
+        assert_eq!(self.len, other.len, "BitSet lengths differ");
+        for (a, &b) in self.words.iter_mut().zip(&other.words) {
+            *a = f(*a, b);
+        }
+    }
+
+    /// `self |= other`
+    pub fn union_with(&mut self, other: &BitSet) {
+        self.zip_with(other, |a, b| a | b);
+    }
+
+    /// `self &= other`
+    pub fn intersect_with(&mut self, other: &BitSet) {
+        self.zip_with(other, |a, b| a & b);
+    }
+
+    /// `self &= !other`
+    pub fn difference_with(&mut self, other: &BitSet) {
+        self.zip_with(other, |a, b| a & !b);
+    }
+
+    /// `self ^= other`
+    pub fn xor_with(&mut self, other: &BitSet) {
+        self.zip_with(other, |a, b| a ^ b);
+    }
+
+    /// 길이 밖으로 밀려난 비트를 지운다.
+    fn clear_tail(&mut self) {
+        if !self.len.is_multiple_of(BITS) {
+            if let Some(last) = self.words.last_mut() {
+                *last &= (1 << (self.len % BITS)) - 1;
+            }
+        }
+    }
+}
+
+pub struct IterOnes<'a> {
+    words: &'a [u64],
+    /// 아직 내보내지 않은 비트만 남은 현재 word
+    word: u64,
+    /// `words[0]`의 첫 비트 번호
+    base: usize,
+}
+
+impl Iterator for IterOnes<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        while self.word == 0 {
+            self.words = self.words.get(1..).filter(|rest| !rest.is_empty())?;
+            self.word = self.words[0];
+            self.base += BITS;
+        }
+        let bit = self.word.trailing_zeros() as usize;
+        self.word &= self.word - 1;
+        Some(self.base + bit)
+    }
+}
+
+/// 비트 `i`를 `i + by`로 옮긴다.
+impl ShlAssign<usize> for BitSet {
+    fn shl_assign(&mut self, by: usize) {
+        let (words, bits) = (by / BITS, by % BITS);
+        for i in (0..self.words.len()).rev() {
+            let hi = i.checked_sub(words).map_or(0, |j| self.words[j] << bits);
+            let lo = match i.checked_sub(words + 1) {
+                Some(j) if bits > 0 => self.words[j] >> (BITS - bits),
+                _ => 0,
+            };
+            self.words[i] = hi | lo;
+        }
+        self.clear_tail();
+    }
+}
+
+/// 비트 `i`를 `i - by`로 옮긴다.
+impl ShrAssign<usize> for BitSet {
+    fn shr_assign(&mut self, by: usize) {
+        let (words, bits) = (by / BITS, by % BITS);
+        let n = self.words.len();
+        for i in 0..n {
+            let lo = self.words.get(i + words).map_or(0, |w| w >> bits);
+            let hi = match self.words.get(i + words + 1) {
+                Some(w) if bits > 0 => w << (BITS - bits),
+                _ => 0,
+            };
+            self.words[i] = lo | hi;
+        }
+    }
+}
+
+impl Shl<usize> for &BitSet {
+    type Output = BitSet;
+
+    fn shl(self, by: usize) -> BitSet {
+        let mut shifted = self.clone();
+        shifted <<= by;
+        shifted
+    }
+}
+
+impl Shr<usize> for &BitSet {
+    type Output = BitSet;
+
+    fn shr(self, by: usize) -> BitSet {
+        let mut shifted = self.clone();
+        shifted >>= by;
+        shifted
+    }
+}
+
+/// 길이는 가장 큰 번호 + 1
+impl FromIterator<usize> for BitSet {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let ones: Vec<usize> = iter.into_iter().collect();
+        let mut set = BitSet::new(ones.iter().max().map_or(0, |&max| max + 1));
+        ones.into_iter().for_each(|i| set.set(i));
+        set
+    }
+}
+
+/// 0번 비트부터 `0`/`1`로
+impl fmt::Debug for BitSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let bits: String = (0..self.len)
+            .map(|i| if self.get(i) { '1' } else { '0' })
+            .collect();
+        f.write_str(&bits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Instant;
+
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    use super::*;
+
+    fn random(rng: &mut StdRng, len: usize) -> (BitSet, Vec<bool>) {
+        let model: Vec<bool> = (0..len).map(|_| rng.gen_bool(0.3)).collect();
+        let mut set = BitSet::new(len);
+        model
+            .iter()
+            .enumerate()
+            .filter(|(_, &b)| b)
+            .for_each(|(i, _)| set.set(i));
+        (set, model)
+    }
+
+    /// 제자리 집합 연산과 그에 맞는 비트 연산
+    type SetOp = (fn(&mut BitSet, &BitSet), fn(bool, bool) -> bool);
+
+    fn ones(model: &[bool]) -> Vec<usize> {
+        (0..model.len()).filter(|&i| model[i]).collect()
+    }
+
+    #[test]
+    fn get_set_reset() {
+        let mut set = BitSet::new(130);
+        set.set(0);
+        set.set(64);
+        set.set(129);
+        assert!(set.get(64) && !set.get(65));
+        set.reset(64);
+        assert_eq!(vec![0, 129], set.iter_ones().collect::<Vec<_>>());
+        assert_eq!(2, set.count_ones());
+        assert!(BitSet::new(0).is_empty());
+        assert_eq!(None, BitSet::new(0).iter_ones().next());
+    }
+
+    #[test]
+    #[should_panic(expected = "index 3 out of range for BitSet of length 3")]
+    fn out_of_range() {
+        BitSet::new(3).set(3);
+    }
+
+    #[test]
+    fn same_as_vec_bool() {
+        let mut rng = StdRng::seed_from_u64(48);
+        for _ in 0..300 {
+            let len = rng.gen_range(0..200);
+            let (a, model_a) = random(&mut rng, len);
+            let (b, model_b) = random(&mut rng, len);
+
+            assert_eq!(ones(&model_a), a.iter_ones().collect::<Vec<_>>());
+            assert_eq!(model_a.iter().filter(|&&x| x).count(), a.count_ones());
+            for i in 0..len + 2 {
+                let expected = (i + 1..len).find(|&j| model_a[j]);
+                assert_eq!(expected, a.first_set_after(i), "{a:?} after {i}");
+            }
+
+            let ops: [SetOp; 4] = [
+                (BitSet::union_with, |x, y| x | y),
+                (BitSet::intersect_with, |x, y| x & y),
+                (BitSet::difference_with, |x, y| x & !y),
+                (BitSet::xor_with, |x, y| x ^ y),
+            ];
+            for (op, model_op) in ops {
+                let mut c = a.clone();
+                op(&mut c, &b);
+                let expected: Vec<bool> =
+                    (0..len).map(|i| model_op(model_a[i], model_b[i])).collect();
+                assert_eq!(ones(&expected), c.iter_ones().collect::<Vec<_>>());
+            }
+
+            let by = rng.gen_range(0..len + 70);
+            let left: Vec<usize> = ones(&model_a)
+                .into_iter()
+                .map(|i| i + by)
+                .filter(|&i| i < len)
+                .collect();
+            let right: Vec<usize> = ones(&model_a)
+                .into_iter()
+                .filter_map(|i| i.checked_sub(by))
+                .collect();
+            assert_eq!(
+                left,
+                (&a << by).iter_ones().collect::<Vec<_>>(),
+                "{a:?} << {by}"
+            );
+            assert_eq!(
+                right,
+                (&a >> by).iter_ones().collect::<Vec<_>>(),
+                "{a:?} >> {by}"
+            );
+            assert_eq!(left.len(), (&a << by).count_ones());
+        }
+    }
+
+    #[test]
+    fn collect_and_debug() {
+        let set: BitSet = [2, 0, 2, 6].into_iter().collect();
+        assert_eq!(7, set.len());
+        assert_eq!("1010001", format!("{set:?}"));
+        assert_eq!("", format!("{:?}", BitSet::from_iter([])));
+    }
+
+    /// `cargo test --release -p boj bitset::tests::bench -- --ignored --nocapture`
+    #[test]
+    #[ignore]
+    fn bench() {
+        const LEN: usize = 1 << 22;
+        const ROUNDS: usize = 20;
+        let mut rng = StdRng::seed_from_u64(0);
+        let (a, model_a) = random(&mut rng, LEN);
+        let (b, model_b) = random(&mut rng, LEN);
+
+        let start = Instant::now();
+        let mut total = 0;
+        for _ in 0..ROUNDS {
+            let mut c = a.clone();
+            c.xor_with(&b);
+            total += c.count_ones() + c.iter_ones().step_by(1000).count();
+        }
+        let bitset_time = start.elapsed();
+
+        let start = Instant::now();
+        let mut expected = 0;
+        for _ in 0..ROUNDS {
+            let c: Vec<bool> = model_a.iter().zip(&model_b).map(|(&x, &y)| x ^ y).collect();
+            expected += c.iter().filter(|&&x| x).count();
+            expected += (0..LEN).filter(|&i| c[i]).step_by(1000).count();
+        }
+        let vec_time = start.elapsed();
+
+        assert_eq!(expected, total);
+        println!("xor + count + iterate {LEN} bits x {ROUNDS}: BitSet {bitset_time:.2?}, Vec<bool> {vec_time:.2?}");
+    }
+}
@@ -0,0 +1,55 @@
+use super::{Graph, NegativeCycle, SingleSource, Weight};
+
+impl<W: Weight> Graph<W> {
+    /// O(VE). 음수 간선도 된다. `source`에서 닿는 음수 사이클이 있으면 에러.
+    pub fn bellman_ford(&self, source: usize) -> Result<SingleSource<W>, NegativeCycle> {
+        let n = self.vertex_count();
+        let mut tree = SingleSource::new(n, source);
+
+        // 최단 경로는 간선을 최대 n - 1개 쓰므로 n번째에도 줄어들면 음수 사이클이다.
+        for round in 0..n {
+            let mut changed = None;
+            for (from, to, weight) in self.edges() {
+                if tree.dist[from] != W::INF && tree.relax(from, to, weight) {
+                    changed = Some(to);
+                }
+            }
+            match changed {
+                None => break,
+                Some(vertex) if round == n - 1 => return Err(NegativeCycle { vertex }),
+                Some(_) => {}
+            }
+        }
+        Ok(tree)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn negative_edge_without_cycle() {
+        let mut graph = Graph::list(3);
+        graph.add_edge(0, 1, 4);
+        graph.add_edge(0, 2, 5);
+        graph.add_edge(2, 1, -3);
+
+        let tree = graph.bellman_ford(0).unwrap();
+        assert_eq!(&[0, 2, 5], tree.dists());
+        assert_eq!(Some(vec![0, 2, 1]), tree.path(1));
+    }
+
+    #[test]
+    fn unreachable_cycle_is_ignored() {
+        let mut graph = Graph::list(3);
+        graph.add_edge(0, 1, 1);
+        graph.add_undirected_edge(2, 2, -1);
+
+        assert!(graph.bellman_ford(0).is_ok());
+        assert_eq!(
+            Err(NegativeCycle { vertex: 2 }),
+            graph.bellman_ford(2).map(|_| ())
+        );
+    }
+}
@@ -0,0 +1,43 @@
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+use super::{Graph, SingleSource, Weight};
+
+impl<W: Weight> Graph<W> {
+    /// O(E log V). 가중치가 음수가 아니어야 한다.
+    pub fn dijkstra(&self, source: usize) -> SingleSource<W> {
+        let mut tree = SingleSource::new(self.vertex_count(), source);
+        let mut heap = BinaryHeap::from([Reverse((W::ZERO, source))]);
+
+        while let Some(Reverse((dist, from))) = heap.pop() {
+            if dist > tree.dist[from] {
+                continue;
+            }
+            for (to, weight) in self.neighbors(from) {
+                debug_assert!(weight >= W::ZERO, "negative edge {from} -> {to}");
+                if tree.relax(from, to, weight) {
+                    heap.push(Reverse((tree.dist[to], to)));
+                }
+            }
+        }
+        tree
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn takes_cheaper_detour() {
+        let mut graph = Graph::list(4);
+        graph.add_edge(0, 1, 10u64);
+        graph.add_edge(0, 2, 1);
+        graph.add_edge(2, 1, 2);
+        graph.add_edge(1, 3, 1);
+
+        let tree = graph.dijkstra(0);
+        assert_eq!(&[0, 3, 1, 4], tree.dists());
+        assert_eq!(Some(vec![0, 2, 1, 3]), tree.path(3));
+        assert_eq!(None, graph.dijkstra(3).path(0));
+    }
+}
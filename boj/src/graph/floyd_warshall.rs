@@ -0,0 +1,196 @@
+use std::io::{self, Write};
+
+use super::{Graph, NegativeCycle, Weight};
+use crate::{
+    output::{Emit, Output},
+    table::Table2D,
+};
+
+/// 모든 정점 쌍 사이의 최단 거리. [`Graph::floyd_warshall`]이 만든다.
+///
+/// 경로는 "`i`에서 `j`로 갈 때 `i` 바로 다음 정점"을 담은 행렬로 되짚으므로 O(V²) 공간에
+/// 모든 쌍의 경로를 담는다.
+#[derive(Clone, Debug)]
+pub struct AllPairs<W> {
+    dist: Table2D<W>,
+    next: Table2D<Option<usize>>,
+}
+
+/// [`AllPairs::write`]의 출력 형식. 정점 번호는 문제처럼 1부터 센다.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    /// BOJ 11404: 거리 행렬. 갈 수 없으면 0.
+    Distances,
+    /// BOJ 11780: 거리 행렬 다음에 모든 쌍 `(i, j)`마다 한 줄씩 경로의 정점 수와 정점들.
+    /// 갈 수 없거나 `i == j`이면 0.
+    Paths,
+}
+
+impl<W: Weight> AllPairs<W> {
+    pub fn vertex_count(&self) -> usize {
+        self.dist.rows()
+    }
+
+    pub fn reachable(&self, from: usize, to: usize) -> bool {
+        self.next[(from, to)].is_some()
+    }
+
+    /// 닿지 않으면 `None`
+    pub fn dist(&self, from: usize, to: usize) -> Option<W> {
+        self.reachable(from, to).then(|| self.dist[(from, to)])
+    }
+
+    /// `from`부터 `to`까지 지나는 정점들. 양 끝을 포함하고, `from == to`이면 `[from]`.
+    pub fn path(&self, from: usize, to: usize) -> Option<Vec<usize>> {
+        self.next[(from, to)]?;
+        let mut path = vec![from];
+        let mut at = from;
+        while at != to {
+            at = self.next[(at, to)]?;
+            path.push(at);
+        }
+        Some(path)
+    }
+
+    pub fn write<O: Write>(&self, out: &mut Output<O>, format: Format) -> io::Result<()>
+    where
+        W: Emit,
+    {
+        let n = self.vertex_count();
+        for from in 0..n {
+            for to in 0..n {
+                if to > 0 {
+                    out.put(" ")?;
+                }
+                match self.dist(from, to) {
+                    Some(dist) => out.put(&dist)?,
+                    None => out.put(&0)?,
+                }
+            }
+            out.put("\n")?;
+        }
+        if format == Format::Distances {
+            return Ok(());
+        }
+
+        for from in 0..n {
+            for to in 0..n {
+                match self.path(from, to).filter(|_| from != to) {
+                    Some(path) => {
+                        out.put(&path.len())?;
+                        for vertex in path {
+                            out.put(" ")?;
+                            out.put(&(vertex + 1))?;
+                        }
+                    }
+                    None => out.put(&0)?,
+                }
+                out.put("\n")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<W: Weight> Graph<W> {
+    /// O(V³). 음수 간선은 괜찮지만 음수 사이클이 있으면 그 위의 정점과 함께 에러.
+    pub fn floyd_warshall(&self) -> Result<AllPairs<W>, NegativeCycle> {
+        let n = self.vertex_count();
+        let mut dist = Table2D::new(n, n, W::INF);
+        let mut next = Table2D::new(n, n, None);
+        for i in 0..n {
+            dist[(i, i)] = W::ZERO;
+            next[(i, i)] = Some(i);
+        }
+        for (from, to, weight) in self.edges() {
+            if weight < dist[(from, to)] {
+                dist[(from, to)] = weight;
+                next[(from, to)] = Some(to);
+            }
+        }
+
+        for k in 0..n {
+            for i in 0..n {
+                // SAFETY: i, j, k < n이고 두 표는 n×n이다.
+                let via_k = unsafe { *dist.get_unchecked(i, k) };
+                if via_k == W::INF {
+                    continue;
+                }
+                for j in 0..n {
+                    unsafe {
+                        let candidate = via_k.plus(*dist.get_unchecked(k, j));
+                        if candidate < *dist.get_unchecked(i, j) {
+                            *dist.get_unchecked_mut(i, j) = candidate;
+                            *next.get_unchecked_mut(i, j) = *next.get_unchecked(i, k);
+                        }
+                    }
+                }
+            }
+        }
+
+        match (0..n).find(|&i| dist[(i, i)] < W::ZERO) {
+            Some(vertex) => Err(NegativeCycle { vertex }),
+            None => Ok(AllPairs { dist, next }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> AllPairs<i32> {
+        let mut graph = Graph::matrix(4);
+        graph.add_edge(0, 1, 5);
+        graph.add_edge(0, 2, 1);
+        graph.add_edge(2, 1, 2);
+        graph.add_edge(1, 3, -1);
+        graph.floyd_warshall().unwrap()
+    }
+
+    #[test]
+    fn shortest_paths() {
+        let all = sample();
+        assert_eq!(Some(2), all.dist(0, 3));
+        assert_eq!(Some(vec![0, 2, 1, 3]), all.path(0, 3));
+        assert_eq!(Some(vec![1]), all.path(1, 1));
+        assert_eq!(Some(0), all.dist(3, 3));
+        assert!(!all.reachable(3, 0));
+        assert_eq!(None, all.dist(3, 0));
+        assert_eq!(None, all.path(3, 0));
+    }
+
+    #[test]
+    fn write_formats() {
+        let all = sample();
+        let mut out = Output::new(vec![]);
+        all.write(&mut out, Format::Distances).unwrap();
+        let distances = "0 3 1 2\n0 0 0 -1\n0 2 0 1\n0 0 0 0\n";
+        assert_eq!(
+            distances,
+            String::from_utf8(out.into_inner().unwrap()).unwrap()
+        );
+
+        let mut out = Output::new(vec![]);
+        all.write(&mut out, Format::Paths).unwrap();
+        let output = String::from_utf8(out.into_inner().unwrap()).unwrap();
+        let (head, paths) = output.split_at(distances.len());
+        assert_eq!(distances, head);
+        let paths: Vec<_> = paths.lines().collect();
+        assert_eq!(16, paths.len());
+        assert_eq!(["0", "3 1 3 2", "2 1 3", "4 1 3 2 4"], paths[..4]);
+        assert_eq!(["0"; 4], paths[12..]);
+    }
+
+    #[test]
+    fn negative_cycle() {
+        let mut graph = Graph::list(3);
+        graph.add_edge(0, 1, 1);
+        graph.add_edge(1, 2, -3);
+        graph.add_edge(2, 1, 1);
+        assert!(matches!(
+            graph.floyd_warshall(),
+            Err(NegativeCycle { vertex: 1 | 2 })
+        ));
+    }
+}
@@ -0,0 +1,354 @@
+//! 가중치 그래프와 최단 경로 알고리즘.
+//!
+//! 정점은 `0..n`으로 센다. 문제가 1부터 세면 읽을 때 1을 빼자.
+//!
+//! | 알고리즘 | 메서드 | 조건 |
+//! |---|---|---|
+//! | Floyd–Warshall | [`Graph::floyd_warshall`] | 음수 사이클이 있으면 에러 |
+//! | Dijkstra | [`Graph::dijkstra`] | 가중치가 음수가 아니어야 한다 |
+//! | Bellman–Ford | [`Graph::bellman_ford`] | 시작점에서 닿는 음수 사이클이 있으면 에러 |
+//! | 0-1 BFS | [`Graph::zero_one_bfs`] | 가중치가 [`Weight::ZERO`] 또는 [`Weight::ONE`] |
+//!
+//! ```
+//! use boj::graph::Graph;
+//!
+//! let mut graph = Graph::list(3);
+//! graph.add_edge(0, 1, 4);
+//! graph.add_edge(1, 2, 1);
+//! graph.add_edge(0, 2, 7);
+//!
+//! let tree = graph.dijkstra(0);
+//! assert_eq!(Some(5), tree.dist(2));
+//! assert_eq!(Some(vec![0, 1, 2]), tree.path(2));
+//! ```
+
+mod bellman_ford;
+mod dijkstra;
+mod floyd_warshall;
+mod zero_one_bfs;
+
+use std::{
+    error::Error,
+    fmt::{self, Debug, Display},
+    iter::Enumerate,
+    slice,
+};
+
+use crate::table::Table2D;
+
+pub use floyd_warshall::{AllPairs, Format};
+
+/// 간선 가중치. [`Weight::INF`]는 "갈 수 없음"이고, 무엇을 더해도 `INF`로 남는다.
+pub trait Weight: Copy + Ord + Debug {
+    const ZERO: Self;
+    const ONE: Self;
+    const INF: Self;
+
+    /// 한쪽이 `INF`이면 `INF`. 넘치면 `INF`(음수 쪽이면 표현할 수 있는 최솟값)에서 멈춘다.
+    fn plus(self, rhs: Self) -> Self;
+}
+
+macro_rules! impl_weight {
+    ($($t:ty),*) => {$(
+        impl Weight for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+            const INF: Self = <$t>::MAX;
+
+            fn plus(self, rhs: Self) -> Self {
+                if self == Self::INF || rhs == Self::INF {
+                    Self::INF
+                } else {
+                    self.saturating_add(rhs)
+                }
+            }
+        }
+    )*};
+}
+
+impl_weight!(i32, i64, isize, u32, u64, usize);
+
+#[derive(Clone, Debug)]
+enum Edges<W> {
+    List(Vec<Vec<(usize, W)>>),
+    /// 간선이 없으면 `INF`
+    Matrix(Table2D<W>),
+}
+
+/// 방향 그래프. 무방향 간선은 [`Graph::add_undirected_edge`]로 양쪽에 넣는다.
+///
+/// - [`Graph::list`]: 인접 리스트. 평행 간선을 모두 기억한다. 간선이 적을 때 쓴다.
+/// - [`Graph::matrix`]: 인접 행렬. 평행 간선은 가장 가벼운 것만 남는다.
+///   정점이 적고 간선이 많을 때(Floyd–Warshall 등) 쓴다.
+#[derive(Clone, Debug)]
+pub struct Graph<W> {
+    edges: Edges<W>,
+}
+
+impl<W: Weight> Graph<W> {
+    pub fn list(n: usize) -> Self {
+        Self {
+            edges: Edges::List(vec![vec![]; n]),
+        }
+    }
+
+    pub fn matrix(n: usize) -> Self {
+        Self {
+            edges: Edges::Matrix(Table2D::new(n, n, W::INF)),
+        }
+    }
+
+    pub fn vertex_count(&self) -> usize {
+        match &self.edges {
+            Edges::List(adj) => adj.len(),
+            Edges::Matrix(adj) => adj.rows(),
+        }
+    }
+
+    /// `from`에서 `to`로 가는 간선. `weight`가 `INF`이면 간선이 없는 것과 같다.
+    pub fn add_edge(&mut self, from: usize, to: usize, weight: W) {
+        match &mut self.edges {
+            Edges::List(adj) => adj[from].push((to, weight)),
+            Edges::Matrix(adj) => {
+                let old = &mut adj[(from, to)];
+                *old = (*old).min(weight);
+            }
+        }
+    }
+
+    pub fn add_undirected_edge(&mut self, u: usize, v: usize, weight: W) {
+        self.add_edge(u, v, weight);
+        self.add_edge(v, u, weight);
+    }
+
+    /// `from`에서 나가는 `(도착 정점, 가중치)`
+    pub fn neighbors(&self, from: usize) -> Neighbors<'_, W> {
+        Neighbors(match &self.edges {
+            Edges::List(adj) => NeighborsInner::List(adj[from].iter()),
+            Edges::Matrix(adj) => NeighborsInner::Matrix(adj.row(from).iter().enumerate()),
+        })
+    }
+
+    /// 모든 `(출발, 도착, 가중치)`
+    pub fn edges(&self) -> impl Iterator<Item = (usize, usize, W)> + '_ {
+        (0..self.vertex_count())
+            .flat_map(move |from| self.neighbors(from).map(move |(to, w)| (from, to, w)))
+    }
+}
+
+pub struct Neighbors<'a, W>(NeighborsInner<'a, W>);
+
+enum NeighborsInner<'a, W> {
+    List(slice::Iter<'a, (usize, W)>),
+    Matrix(Enumerate<slice::Iter<'a, W>>),
+}
+
+impl<W: Weight> Iterator for Neighbors<'_, W> {
+    type Item = (usize, W);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.0 {
+            NeighborsInner::List(iter) => iter.next().copied(),
+            NeighborsInner::Matrix(iter) => {
+                iter.find(|(_, &w)| w != W::INF).map(|(to, &w)| (to, w))
+            }
+        }
+    }
+}
+
+/// 음수 사이클 위(또는 그 사이클에서 닿는 곳)에 있는 정점 하나를 담는다.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NegativeCycle {
+    pub vertex: usize,
+}
+
+impl Display for NegativeCycle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "negative cycle through vertex {}", self.vertex)
+    }
+}
+
+impl Error for NegativeCycle {}
+
+/// 한 시작점에서의 최단 경로 트리
+#[derive(Clone, Debug)]
+pub struct SingleSource<W> {
+    source: usize,
+    dist: Vec<W>,
+    /// 최단 경로에서 바로 앞 정점
+    prev: Vec<Option<usize>>,
+}
+
+impl<W: Weight> SingleSource<W> {
+    fn new(n: usize, source: usize) -> Self {
+        let mut dist = vec![W::INF; n];
+        dist[source] = W::ZERO;
+        Self {
+            source,
+            dist,
+            prev: vec![None; n],
+        }
+    }
+
+    pub fn source(&self) -> usize {
+        self.source
+    }
+
+    pub fn reachable(&self, to: usize) -> bool {
+        self.dist[to] != W::INF
+    }
+
+    /// 닿지 않으면 `None`
+    pub fn dist(&self, to: usize) -> Option<W> {
+        self.reachable(to).then(|| self.dist[to])
+    }
+
+    /// 정점마다의 거리. 닿지 않으면 `INF`.
+    pub fn dists(&self) -> &[W] {
+        &self.dist
+    }
+
+    /// 시작점부터 `to`까지 지나는 정점들. 닿지 않으면 `None`.
+    pub fn path(&self, to: usize) -> Option<Vec<usize>> {
+        if !self.reachable(to) {
+            return None;
+        }
+        let mut path = vec![to];
+        let mut at = to;
+        while let Some(prev) = self.prev[at] {
+            path.push(prev);
+            at = prev;
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// `from -> to` 간선으로 `to`까지의 거리를 줄일 수 있으면 줄이고 `true`
+    fn relax(&mut self, from: usize, to: usize, weight: W) -> bool {
+        let candidate = self.dist[from].plus(weight);
+        if candidate < self.dist[to] {
+            self.dist[to] = candidate;
+            self.prev[to] = Some(from);
+            true
+        } else {
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    use super::*;
+
+    #[test]
+    fn plus_saturates_at_infinity() {
+        assert_eq!(i64::INF, i64::INF.plus(-5));
+        assert_eq!(i64::INF, 3.plus(i64::INF));
+        assert_eq!(u32::INF, (u32::MAX - 1).plus(7));
+        assert_eq!(i32::MIN, (i32::MIN + 1).plus(-7));
+        assert_eq!(5, 2usize.plus(3));
+    }
+
+    #[test]
+    fn backends_agree_on_neighbors() {
+        let mut list = Graph::list(3);
+        let mut matrix = Graph::matrix(3);
+        for graph in [&mut list, &mut matrix] {
+            graph.add_edge(0, 2, 5);
+            graph.add_edge(0, 2, 3);
+            graph.add_undirected_edge(1, 2, 1);
+        }
+        assert_eq!(vec![(2, 5), (2, 3)], list.neighbors(0).collect::<Vec<_>>());
+        // 행렬은 가장 가벼운 간선만 남긴다.
+        assert_eq!(vec![(2, 3)], matrix.neighbors(0).collect::<Vec<_>>());
+        assert_eq!(
+            vec![(0, 2, 3), (1, 2, 1), (2, 1, 1)],
+            matrix.edges().collect::<Vec<_>>()
+        );
+        assert_eq!(3, list.vertex_count());
+    }
+
+    fn random_graph(rng: &mut StdRng, weights: impl Fn(&mut StdRng) -> i64) -> Graph<i64> {
+        let n = rng.gen_range(1..=8);
+        let mut graph = if rng.gen() {
+            Graph::list(n)
+        } else {
+            Graph::matrix(n)
+        };
+        for _ in 0..rng.gen_range(0..=n * n) {
+            let (from, to) = (rng.gen_range(0..n), rng.gen_range(0..n));
+            graph.add_edge(from, to, weights(rng));
+        }
+        graph
+    }
+
+    /// 경로의 간선 가중치 합이 거리와 같은지
+    fn path_weight(graph: &Graph<i64>, path: &[usize]) -> i64 {
+        path.windows(2)
+            .map(|w| {
+                graph
+                    .neighbors(w[0])
+                    .filter(|&(to, _)| to == w[1])
+                    .map(|(_, weight)| weight)
+                    .min()
+                    .expect("path uses a missing edge")
+            })
+            .sum()
+    }
+
+    fn assert_same(graph: &Graph<i64>, expected: &AllPairs<i64>, actual: &SingleSource<i64>) {
+        let from = actual.source();
+        for to in 0..graph.vertex_count() {
+            assert_eq!(expected.dist(from, to), actual.dist(to), "{graph:?}");
+            if let Some(path) = actual.path(to) {
+                assert_eq!((from, to), (path[0], *path.last().unwrap()));
+                assert_eq!(actual.dist(to), Some(path_weight(graph, &path)));
+            }
+        }
+    }
+
+    #[test]
+    fn algorithms_agree_with_floyd_warshall() {
+        let mut rng = StdRng::seed_from_u64(41);
+        for _ in 0..300 {
+            let graph = random_graph(&mut rng, |rng| rng.gen_range(0..=20));
+            let all = graph.floyd_warshall().unwrap();
+            for from in 0..graph.vertex_count() {
+                assert_same(&graph, &all, &graph.dijkstra(from));
+                assert_same(&graph, &all, &graph.bellman_ford(from).unwrap());
+                for to in 0..graph.vertex_count() {
+                    if let Some(path) = all.path(from, to) {
+                        assert_eq!(all.dist(from, to), Some(path_weight(&graph, &path)));
+                    }
+                }
+            }
+
+            let binary = random_graph(&mut rng, |rng| rng.gen_range(0..=1));
+            let all = binary.floyd_warshall().unwrap();
+            for from in 0..binary.vertex_count() {
+                assert_same(&binary, &all, &binary.zero_one_bfs(from));
+            }
+        }
+    }
+
+    #[test]
+    fn negative_weights_agree() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..300 {
+            let graph = random_graph(&mut rng, |rng| rng.gen_range(-5..=20));
+            match graph.floyd_warshall() {
+                Ok(all) => {
+                    for from in 0..graph.vertex_count() {
+                        assert_same(&graph, &all, &graph.bellman_ford(from).unwrap());
+                    }
+                }
+                // 어딘가에 음수 사이클이 있으면 그 정점에서 시작한 Bellman–Ford도 찾아야 한다.
+                Err(NegativeCycle { vertex }) => {
+                    assert!(graph.bellman_ford(vertex).is_err(), "{graph:?}");
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use std::collections::VecDeque;
+
+use super::{Graph, SingleSource, Weight};
+
+impl<W: Weight> Graph<W> {
+    /// O(V + E). 가중치가 `ZERO`인 간선은 덱 앞에, `ONE`인 간선은 뒤에 넣는다.
+    ///
+    /// # Panics
+    /// 가중치가 `ZERO`도 `ONE`도 아닌 간선을 만나면 panic한다.
+    pub fn zero_one_bfs(&self, source: usize) -> SingleSource<W> {
+        let mut tree = SingleSource::new(self.vertex_count(), source);
+        let mut deque = VecDeque::from([source]);
+        let mut done = vec![false; self.vertex_count()];
+
+        while let Some(from) = deque.pop_front() {
+            if std::mem::replace(&mut done[from], true) {
+                continue;
+            }
+            for (to, weight) in self.neighbors(from) {
+                assert!(
+                    weight == W::ZERO || weight == W::ONE,
+                    "0-1 BFS got weight {weight:?} on {from} -> {to}"
+                );
+                if tree.relax(from, to, weight) {
+                    if weight == W::ZERO {
+                        deque.push_front(to);
+                    } else {
+                        deque.push_back(to);
+                    }
+                }
+            }
+        }
+        tree
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prefers_free_edges() {
+        let mut graph = Graph::list(4);
+        graph.add_edge(0, 3, 1);
+        graph.add_edge(0, 1, 0);
+        graph.add_edge(1, 2, 0);
+        graph.add_edge(2, 3, 0);
+
+        let tree = graph.zero_one_bfs(0);
+        assert_eq!(&[0, 0, 0, 0], tree.dists());
+        assert_eq!(Some(vec![0, 1, 2, 3]), tree.path(3));
+    }
+
+    #[test]
+    #[should_panic(expected = "0-1 BFS got weight 2")]
+    fn rejects_other_weights() {
+        let mut graph = Graph::list(2);
+        graph.add_edge(0, 1, 2);
+        graph.zero_one_bfs(0);
+    }
+}
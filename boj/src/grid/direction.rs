@@ -0,0 +1,176 @@
+use std::{
+    fmt::Debug,
+    ops::{Add, Neg},
+};
+
+use super::Position;
+
+/// 격자 위의 방향. [`Direction::ALL`]은 북쪽부터 시계 방향 순서다.
+pub trait Direction: Copy + Eq + Debug + 'static {
+    const ALL: &'static [Self];
+
+    /// `ALL`에서의 위치
+    fn index(self) -> usize;
+
+    /// `(행, 열)` 변화량. 북쪽은 행이 줄어드는 쪽이다.
+    fn delta(self) -> (isize, isize);
+
+    /// 시계 방향으로 한 칸
+    fn cw(self) -> Self {
+        Self::ALL[(self.index() + 1) % Self::ALL.len()]
+    }
+
+    /// 반시계 방향으로 한 칸
+    fn ccw(self) -> Self {
+        Self::ALL[(self.index() + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+
+    fn opposite(self) -> Self {
+        Self::ALL[(self.index() + Self::ALL.len() / 2) % Self::ALL.len()]
+    }
+}
+
+/// 상하좌우. BOJ 문제들처럼 북, 동, 남, 서가 0, 1, 2, 3이다.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Dir4 {
+    North = 0,
+    East = 1,
+    South = 2,
+    West = 3,
+}
+
+/// 대각선을 포함한 여덟 방향
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Dir8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction for Dir4 {
+    const ALL: &'static [Self] = &[Dir4::North, Dir4::East, Dir4::South, Dir4::West];
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    fn delta(self) -> (isize, isize) {
+        match self {
+            Dir4::North => (-1, 0),
+            Dir4::East => (0, 1),
+            Dir4::South => (1, 0),
+            Dir4::West => (0, -1),
+        }
+    }
+}
+
+impl Direction for Dir8 {
+    const ALL: &'static [Self] = &[
+        Dir8::North,
+        Dir8::NorthEast,
+        Dir8::East,
+        Dir8::SouthEast,
+        Dir8::South,
+        Dir8::SouthWest,
+        Dir8::West,
+        Dir8::NorthWest,
+    ];
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    fn delta(self) -> (isize, isize) {
+        match self {
+            Dir8::North => (-1, 0),
+            Dir8::NorthEast => (-1, 1),
+            Dir8::East => (0, 1),
+            Dir8::SouthEast => (1, 1),
+            Dir8::South => (1, 0),
+            Dir8::SouthWest => (1, -1),
+            Dir8::West => (0, -1),
+            Dir8::NorthWest => (-1, -1),
+        }
+    }
+}
+
+/// 입력의 방향 번호. 범위를 벗어나면 4로 나눈 나머지로 본다.
+impl From<isize> for Dir4 {
+    fn from(value: isize) -> Self {
+        Self::ALL[value.rem_euclid(Self::ALL.len() as isize) as usize]
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(dir: Dir4) -> Self {
+        Self::ALL[dir.index() * 2]
+    }
+}
+
+impl Neg for Dir4 {
+    type Output = Dir4;
+
+    fn neg(self) -> Self::Output {
+        self.opposite()
+    }
+}
+
+impl Neg for Dir8 {
+    type Output = Dir8;
+
+    fn neg(self) -> Self::Output {
+        self.opposite()
+    }
+}
+
+macro_rules! impl_add_direction {
+    ($($dir:ty),*) => {$(
+        impl Add<$dir> for Position {
+            type Output = Position;
+
+            fn add(self, rhs: $dir) -> Self::Output {
+                let (dr, dc) = rhs.delta();
+                Position(self.0 + dr, self.1 + dc)
+            }
+        }
+    )*};
+}
+
+impl_add_direction!(Dir4, Dir8);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rotations() {
+        assert_eq!(Dir4::West, Dir4::North.ccw());
+        assert_eq!(Dir4::North, Dir4::West.cw());
+        assert_eq!(Dir8::NorthWest, Dir8::North.ccw());
+        assert_eq!(Dir8::SouthWest, Dir8::NorthEast.opposite());
+        for &dir in Dir8::ALL {
+            assert_eq!(dir, dir.cw().ccw());
+            assert_eq!(dir, -(-dir));
+            let (dr, dc) = dir.delta();
+            assert_eq!((-dr, -dc), dir.opposite().delta());
+        }
+    }
+
+    #[test]
+    fn from_number() {
+        assert_eq!(Dir4::West, Dir4::from(-1));
+        assert_eq!(Dir4::South, Dir4::from(6));
+        assert_eq!(Dir8::East, Dir8::from(Dir4::East));
+    }
+
+    #[test]
+    fn position_plus_direction() {
+        assert_eq!(Position(0, 1), Position(1, 1) + Dir4::North);
+        assert_eq!(Position(2, 0), Position(1, 1) + Dir8::SouthWest);
+    }
+}
@@ -0,0 +1,236 @@
+//! 격자 시뮬레이션 도구.
+//!
+//! - [`Grid`]: 크기를 실행 중에 정하는 2차원 격자. 좌표가 음수이거나 밖이면 `None`을 준다.
+//! - [`Dir4`], [`Dir8`]: 네 방향/여덟 방향과 회전
+//! - [`Agent`]: 격자 위에서 한 걸음씩 움직이는 주체. 걸음마다 [`Agent::Event`]를 남긴다.
+//!
+//! ```
+//! use boj::grid::{Dir4, Grid, Position};
+//!
+//! let grid = Grid::from_rows(vec![b"#.".to_vec(), b"..".to_vec()]);
+//! let open: Vec<_> = grid
+//!     .neighbors::<Dir4>(Position(0, 1))
+//!     .filter(|&(_, pos)| grid[pos] == b'.')
+//!     .collect();
+//! assert_eq!(vec![(Dir4::South, Position(1, 1))], open);
+//! ```
+
+mod direction;
+
+use std::{
+    fmt::{self, Debug, Display},
+    ops::{Add, Index, IndexMut},
+};
+
+use crate::table::Table2D;
+
+pub use direction::{Dir4, Dir8, Direction};
+
+/// `(행, 열)`. 격자 밖도 가리킬 수 있도록 부호가 있다.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Position(pub isize, pub isize);
+
+impl Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.0, self.1)
+    }
+}
+
+impl Add for Position {
+    type Output = Position;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Position(self.0 + rhs.0, self.1 + rhs.1)
+    }
+}
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct Grid<C> {
+    cells: Table2D<C>,
+}
+
+impl<C: Clone> Grid<C> {
+    pub fn new(rows: usize, cols: usize, fill: C) -> Self {
+        Self {
+            cells: Table2D::new(rows, cols, fill),
+        }
+    }
+}
+
+impl<C> Grid<C> {
+    /// # Panics
+    /// 행마다 길이가 다르면 panic한다.
+    pub fn from_rows(rows: Vec<Vec<C>>) -> Self {
+        let cols = rows.first().map_or(0, Vec::len);
+        assert!(
+            rows.iter().all(|row| row.len() == cols),
+            "rows have different lengths"
+        );
+        let n = rows.len();
+        let mut cells = rows.into_iter().flatten();
+        Self {
+            cells: Table2D::from_fn(n, cols, |_, _| cells.next().unwrap()),
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.cells.rows()
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cells.cols()
+    }
+
+    pub fn contains(&self, pos: Position) -> bool {
+        self.cells.get_signed(pos.0, pos.1).is_some()
+    }
+
+    pub fn get(&self, pos: Position) -> Option<&C> {
+        self.cells.get_signed(pos.0, pos.1)
+    }
+
+    pub fn get_mut(&mut self, pos: Position) -> Option<&mut C> {
+        let (row, col) = (usize::try_from(pos.0).ok()?, usize::try_from(pos.1).ok()?);
+        self.cells.get_mut(row, col)
+    }
+
+    /// 모든 칸의 좌표. 행 우선 순서다.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let (rows, cols) = (self.rows() as isize, self.cols() as isize);
+        (0..rows).flat_map(move |i| (0..cols).map(move |j| Position(i, j)))
+    }
+
+    /// `pos`에서 `D` 방향으로 한 칸 간 이웃 중 격자 안에 있는 것. 순서는 [`Direction::ALL`]을 따른다.
+    pub fn neighbors<D: Direction>(&self, pos: Position) -> impl Iterator<Item = (D, Position)> + '_
+    where
+        Position: Add<D, Output = Position>,
+    {
+        D::ALL
+            .iter()
+            .map(move |&dir| (dir, pos + dir))
+            .filter(|&(_, next)| self.contains(next))
+    }
+
+    pub fn iter_rows(&self) -> impl Iterator<Item = &[C]> {
+        self.cells.iter_rows()
+    }
+}
+
+impl<C> Index<Position> for Grid<C> {
+    type Output = C;
+
+    fn index(&self, pos: Position) -> &C {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{pos} is outside of {}x{} grid", self.rows(), self.cols()))
+    }
+}
+
+impl<C> IndexMut<Position> for Grid<C> {
+    fn index_mut(&mut self, pos: Position) -> &mut C {
+        let (rows, cols) = (self.rows(), self.cols());
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{pos} is outside of {rows}x{cols} grid"))
+    }
+}
+
+impl<C: Debug> Debug for Grid<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.cells.fmt(f)
+    }
+}
+
+/// 격자 위에서 한 걸음씩 움직이는 시뮬레이션의 주체
+pub trait Agent<C> {
+    /// 한 걸음 동안 일어난 일
+    type Event;
+
+    /// 한 걸음 진행한다. 멈췄으면 `None`이고, 그 뒤로도 계속 `None`이어야 한다.
+    fn step(&mut self, grid: &mut Grid<C>) -> Option<Self::Event>;
+
+    /// 멈출 때까지 돌리며 걸음마다 `on_event`를 부른다. 걸음 수를 돌려준다.
+    fn run(&mut self, grid: &mut Grid<C>, mut on_event: impl FnMut(Self::Event)) -> usize
+    where
+        Self: Sized,
+    {
+        let mut steps = 0;
+        while let Some(event) = self.step(grid) {
+            on_event(event);
+            steps += 1;
+        }
+        steps
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bounds_checked_access() {
+        let mut grid = Grid::new(2, 3, 0);
+        grid[Position(1, 2)] = 7;
+        assert_eq!(Some(&7), grid.get(Position(1, 2)));
+        assert_eq!(None, grid.get(Position(-1, 0)));
+        assert_eq!(None, grid.get(Position(0, 3)));
+        assert!(grid.get_mut(Position(2, 0)).is_none());
+        assert!(!grid.contains(Position(0, -1)));
+        assert_eq!(6, grid.positions().count());
+        assert_eq!(Some(Position(1, 2)), grid.positions().last());
+    }
+
+    #[test]
+    #[should_panic(expected = "(2, 0) is outside of 2x3 grid")]
+    fn index_outside_panics() {
+        let grid = Grid::new(2, 3, 0);
+        let _ = grid[Position(2, 0)];
+    }
+
+    #[test]
+    fn neighbors_stay_inside() {
+        let grid = Grid::new(3, 3, ());
+        let corner: Vec<_> = grid.neighbors::<Dir4>(Position(0, 0)).collect();
+        assert_eq!(
+            vec![(Dir4::East, Position(0, 1)), (Dir4::South, Position(1, 0))],
+            corner
+        );
+        assert_eq!(3, grid.neighbors::<Dir8>(Position(2, 2)).count());
+        assert_eq!(8, grid.neighbors::<Dir8>(Position(1, 1)).count());
+    }
+
+    #[test]
+    #[should_panic(expected = "rows have different lengths")]
+    fn ragged_rows() {
+        Grid::from_rows(vec![vec![1, 2], vec![3]]);
+    }
+
+    /// 벽(`true`)에 막힐 때까지 한 방향으로 가며 지나온 칸을 칠한다.
+    struct Walker(Position, Dir4);
+
+    impl Agent<bool> for Walker {
+        type Event = Position;
+
+        fn step(&mut self, grid: &mut Grid<bool>) -> Option<Position> {
+            let next = self.0 + self.1;
+            if *grid.get(next)? {
+                return None;
+            }
+            grid[next] = true;
+            self.0 = next;
+            Some(next)
+        }
+    }
+
+    #[test]
+    fn agent_runs_until_halt() {
+        let mut grid = Grid::new(1, 5, false);
+        grid[Position(0, 4)] = true;
+        let mut visited = vec![];
+        let steps = Walker(Position(0, 0), Dir4::East).run(&mut grid, |pos| visited.push(pos.1));
+        assert_eq!(3, steps);
+        assert_eq!(vec![1, 2, 3], visited);
+        assert_eq!(
+            vec![&[false, true, true, true, true][..]],
+            grid.iter_rows().collect::<Vec<_>>()
+        );
+    }
+}
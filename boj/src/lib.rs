@@ -0,0 +1,17 @@
+//! `src/bin/*.rs`의 풀이들이 함께 쓰는 도구 모음.
+//!
+//! 문제 풀이는 여전히 `cargo run -p boj --bin <problem_number>`로 실행한다.
+//! 풀이 본체는 [`problems`]에 있고, `cargo run -p boj --bin boj -- run <problem_number>`로도
+//! 실행할 수 있다.
+
+pub mod bitset;
+pub mod graph;
+pub mod grid;
+pub mod output;
+pub mod prefix_sum;
+pub mod problems;
+pub mod scanner;
+pub mod solver;
+pub mod stress;
+pub mod table;
+pub mod torus;
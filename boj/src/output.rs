@@ -0,0 +1,312 @@
+//! 출력을 한 번에 모아서 쓰는 [`Output`].
+//!
+//! `println!`은 부를 때마다 stdout을 잠그고 줄 단위로 flush하기 때문에 출력이 수십만 줄인
+//! 문제에서는 그것만으로 시간 초과가 난다. [`Output`]은 stdout을 한 번만 잠근 채 자체 버퍼에
+//! 모아 두었다가 버퍼가 차거나 drop될 때 내보낸다.
+//!
+//! 정수는 `fmt` 기계를 거치지 않고 바로 자릿수를 찍는 [`Emit`] 구현을 쓴다.
+//!
+//! ```
+//! use boj::{out, outln, output::Output};
+//!
+//! let mut out = Output::new(Vec::new());
+//! outln!(out; 1, -2, "three").unwrap(); // 공백으로 구분
+//! out!(out, "Case #{}: ", 1).unwrap(); // format 문자열
+//! outln!(out; 42u64).unwrap();
+//! assert_eq!("1 -2 three\nCase #1: 42\n", String::from_utf8(out.into_inner().unwrap()).unwrap());
+//! ```
+
+use std::{
+    fmt,
+    io::{self, StdoutLock, Write},
+};
+
+/// 기본 버퍼 크기
+pub const CAPACITY: usize = 1 << 16;
+
+/// 버퍼에 모아 두었다가 한꺼번에 `inner`로 내보내는 writer.
+///
+/// drop될 때 남은 내용을 flush한다. 이때 생긴 에러는 무시되므로 에러를 확인하려면
+/// [`Output::flush`]나 [`Output::into_inner`]를 직접 부른다.
+pub struct Output<W: Write> {
+    buf: Vec<u8>,
+    capacity: usize,
+    /// `into_inner`에서 꺼낸 뒤에는 `None`
+    inner: Option<W>,
+}
+
+/// [`Output`]에 바로 바이트로 찍을 수 있는 값.
+pub trait Emit {
+    fn emit(&self, buf: &mut Vec<u8>);
+}
+
+impl Output<StdoutLock<'static>> {
+    /// 표준 출력을 잠근 채로 쓴다.
+    pub fn stdout() -> Self {
+        Self::new(io::stdout().lock())
+    }
+}
+
+impl<W: Write> Output<W> {
+    pub fn new(inner: W) -> Self {
+        Self::with_capacity(CAPACITY, inner)
+    }
+
+    pub fn with_capacity(capacity: usize, inner: W) -> Self {
+        Self {
+            buf: Vec::with_capacity(capacity),
+            capacity,
+            inner: Some(inner),
+        }
+    }
+
+    /// 버퍼가 다 찼으면 비운다.
+    fn flush_if_full(&mut self) -> io::Result<()> {
+        if self.buf.len() >= self.capacity {
+            self.flush_buf()?;
+        }
+        Ok(())
+    }
+
+    fn flush_buf(&mut self) -> io::Result<()> {
+        if let Some(inner) = self.inner.as_mut() {
+            inner.write_all(&self.buf)?;
+        }
+        self.buf.clear();
+        Ok(())
+    }
+
+    /// 값 하나를 구분자 없이 쓴다.
+    pub fn put<T: Emit + ?Sized>(&mut self, item: &T) -> io::Result<()> {
+        item.emit(&mut self.buf);
+        self.flush_if_full()
+    }
+
+    /// 값들을 공백으로 구분해서 쓴다. 줄바꿈은 붙이지 않는다.
+    pub fn put_all(&mut self, items: &[&dyn Emit]) -> io::Result<()> {
+        for (i, item) in items.iter().enumerate() {
+            if i > 0 {
+                self.buf.push(b' ');
+            }
+            item.emit(&mut self.buf);
+        }
+        self.flush_if_full()
+    }
+
+    /// 값들을 공백으로 구분해서 한 줄로 쓴다.
+    pub fn put_line(&mut self, items: &[&dyn Emit]) -> io::Result<()> {
+        self.put_all(items)?;
+        self.put("\n")
+    }
+
+    /// `write!`와 같다. 매크로에서 `&mut`로 다시 빌리지 않고 부르기 위해 따로 둔다.
+    pub fn put_fmt(&mut self, args: fmt::Arguments<'_>) -> io::Result<()> {
+        self.write_fmt(args)
+    }
+
+    pub fn put_fmt_line(&mut self, args: fmt::Arguments<'_>) -> io::Result<()> {
+        self.write_fmt(args)?;
+        self.put("\n")
+    }
+
+    /// 버퍼를 비우고 `inner`를 돌려준다.
+    pub fn into_inner(mut self) -> io::Result<W> {
+        self.flush_buf()?;
+        Ok(self.inner.take().expect("inner is taken only once"))
+    }
+}
+
+impl<W: Write> Write for Output<W> {
+    fn write(&mut self, bytes: &[u8]) -> io::Result<usize> {
+        self.buf.extend_from_slice(bytes);
+        self.flush_if_full()?;
+        Ok(bytes.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.flush_buf()?;
+        match self.inner.as_mut() {
+            Some(inner) => inner.flush(),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<W: Write> Drop for Output<W> {
+    fn drop(&mut self) {
+        let _ = self.flush();
+    }
+}
+
+impl<W: Write> fmt::Debug for Output<W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Output")
+            .field("buffered", &self.buf.len())
+            .field("capacity", &self.capacity)
+            .finish()
+    }
+}
+
+macro_rules! impl_emit_unsigned {
+    ($($ty:ty),*) => {$(
+        impl Emit for $ty {
+            fn emit(&self, buf: &mut Vec<u8>) {
+                // u128::MAX도 39자리
+                let mut digits = [0u8; 40];
+                let mut pos = digits.len();
+                let mut n = *self;
+                loop {
+                    pos -= 1;
+                    digits[pos] = b'0' + (n % 10) as u8;
+                    n /= 10;
+                    if n == 0 {
+                        break;
+                    }
+                }
+                buf.extend_from_slice(&digits[pos..]);
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_emit_signed {
+    ($($ty:ty),*) => {$(
+        impl Emit for $ty {
+            fn emit(&self, buf: &mut Vec<u8>) {
+                if *self < 0 {
+                    buf.push(b'-');
+                }
+                // `MIN`도 넘치지 않도록 부호 없는 타입에서 절댓값을 구한다.
+                self.unsigned_abs().emit(buf);
+            }
+        }
+    )*};
+}
+
+impl_emit_unsigned!(u8, u16, u32, u64, u128, usize);
+impl_emit_signed!(i8, i16, i32, i64, i128, isize);
+
+impl Emit for str {
+    fn emit(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(self.as_bytes());
+    }
+}
+
+impl Emit for String {
+    fn emit(&self, buf: &mut Vec<u8>) {
+        self.as_str().emit(buf);
+    }
+}
+
+impl Emit for char {
+    fn emit(&self, buf: &mut Vec<u8>) {
+        self.encode_utf8(&mut [0; 4]).emit(buf);
+    }
+}
+
+impl<T: Emit + ?Sized> Emit for &T {
+    fn emit(&self, buf: &mut Vec<u8>) {
+        (**self).emit(buf);
+    }
+}
+
+/// `write!`처럼 쓰거나(`out!(out, "{} ", x)`), 값들을 공백으로 구분해서 쓴다(`out!(out; a, b)`).
+/// `io::Result<()>`를 돌려준다.
+#[macro_export]
+macro_rules! out {
+    ($out:expr; $($item:expr),+ $(,)?) => {
+        $out.put_all(&[$(&$item as &dyn $crate::output::Emit),+])
+    };
+    ($out:expr, $($fmt:tt)+) => {
+        $out.put_fmt(::std::format_args!($($fmt)+))
+    };
+}
+
+/// [`out!`]과 같지만 끝에 줄바꿈을 붙인다.
+#[macro_export]
+macro_rules! outln {
+    ($out:expr) => {
+        $out.put("\n")
+    };
+    ($out:expr; $($item:expr),+ $(,)?) => {
+        $out.put_line(&[$(&$item as &dyn $crate::output::Emit),+])
+    };
+    ($out:expr, $($fmt:tt)+) => {
+        $out.put_fmt_line(::std::format_args!($($fmt)+))
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(f: impl FnOnce(&mut Output<&mut Vec<u8>>) -> io::Result<()>) -> String {
+        let mut sink = vec![];
+        let mut out = Output::new(&mut sink);
+        f(&mut out).unwrap();
+        drop(out);
+        String::from_utf8(sink).unwrap()
+    }
+
+    #[test]
+    fn integers() {
+        let text = render(|out| {
+            outln!(out; 0, 7, -7, 1234567890)?;
+            outln!(out; i8::MIN, i8::MAX, u8::MAX)?;
+            outln!(out; i64::MIN, u64::MAX)?;
+            outln!(out; i128::MIN, u128::MAX)
+        });
+        assert_eq!(
+            format!(
+                "0 7 -7 1234567890\n-128 127 255\n{} {}\n{} {}\n",
+                i64::MIN,
+                u64::MAX,
+                i128::MIN,
+                u128::MAX
+            ),
+            text
+        );
+    }
+
+    #[test]
+    fn emit_matches_display() {
+        for n in (-1000..1000).chain([i32::MIN, i32::MAX]) {
+            let mut buf = vec![];
+            n.emit(&mut buf);
+            assert_eq!(n.to_string().as_bytes(), buf);
+        }
+    }
+
+    #[test]
+    fn mixed_items_and_format() {
+        let name = String::from("robot");
+        let text = render(|out| {
+            out!(out; 'a', "b", name)?;
+            outln!(out)?;
+            out!(out, "Case #{}: ", 3)?;
+            outln!(out; 1, '+', 2)?;
+            outln!(out, "{:>3}|{:<3}|", 1, 2)
+        });
+        assert_eq!("a b robot\nCase #3: 1 + 2\n  1|2  |\n", text);
+    }
+
+    #[test]
+    fn flushes_when_full() {
+        let mut sink = vec![];
+        {
+            let mut out = Output::with_capacity(4, &mut sink);
+            out.put("abc").unwrap();
+            out.put("de").unwrap(); // 4바이트를 넘었으니 내보낸다
+            out.put("f").unwrap();
+            assert_eq!(5, out.inner.as_ref().unwrap().len());
+        }
+        assert_eq!(b"abcdef", &sink[..]);
+    }
+
+    #[test]
+    fn into_inner_flushes() {
+        let mut out = Output::new(vec![]);
+        outln!(out; 1, 2).unwrap();
+        assert_eq!(b"1 2\n", &out.into_inner().unwrap()[..]);
+    }
+}
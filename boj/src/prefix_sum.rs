@@ -0,0 +1,397 @@
+//! 2차원 구간 합.
+//!
+//! - [`PrefixSum2D`]: 값이 바뀌지 않는 격자. 만드는 데 O(NM), 질의는 O(1).
+//! - [`Fenwick2D`]: 한 칸에 값을 더할 수 있는 격자. 더하기와 질의 모두 O(log N log M).
+//!
+//! 둘 다 [`RectSum::sum`]으로 직사각형 안의 합을 구한다. 직사각형은 `(행 범위, 열 범위)`이고,
+//! `a..b`처럼 끝을 빼거나 `a..=b`처럼 끝을 넣어 쓸 수 있다. 좌표는 0부터 센다.
+//!
+//! ```
+//! use boj::prefix_sum::{Fenwick2D, PrefixSum2D, RectSum};
+//!
+//! let grid = vec![vec![1, 2, 3], vec![4, 5, 6]];
+//! let sums = PrefixSum2D::from_rows(&grid);
+//! assert_eq!(2 + 3 + 5 + 6, sums.sum((0..2, 1..3)));
+//! assert_eq!(2 + 3 + 5 + 6, sums.sum((0..=1, 1..=2)));
+//!
+//! let mut tree = Fenwick2D::from_rows(&grid);
+//! tree.add(1, 1, 10);
+//! assert_eq!(15, tree.sum((1..=1, 1..=1)));
+//! ```
+
+use std::{
+    fmt::Debug,
+    ops::{Add, AddAssign, Bound, Range, RangeBounds, RangeInclusive, Sub},
+};
+
+use crate::table::Table2D;
+
+/// 더하고 뺄 수 있는 값. 부호 없는 타입도 되도록 빼기는 결과가 음수가 아닐 때만 한다.
+pub trait Num: Copy + Debug + Add<Output = Self> + Sub<Output = Self> + AddAssign {
+    const ZERO: Self;
+}
+
+macro_rules! impl_num {
+    ($($t:ty),*) => {$(
+        impl Num for $t {
+            const ZERO: Self = 0 as $t;
+        }
+    )*};
+}
+
+impl_num!(i32, i64, i128, isize, u32, u64, u128, usize, f64);
+
+/// `rows`×`cols` 직사각형. 두 범위 모두 끝을 뺀다.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Rect {
+    pub rows: Range<usize>,
+    pub cols: Range<usize>,
+}
+
+impl Rect {
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty() || self.cols.is_empty()
+    }
+}
+
+impl From<(Range<usize>, Range<usize>)> for Rect {
+    fn from((rows, cols): (Range<usize>, Range<usize>)) -> Self {
+        Rect { rows, cols }
+    }
+}
+
+/// # Panics
+/// 끝이 `usize::MAX`면 끝을 뺀 범위로 바꿀 수 없어서 panic한다.
+impl From<(RangeInclusive<usize>, RangeInclusive<usize>)> for Rect {
+    fn from((rows, cols): (RangeInclusive<usize>, RangeInclusive<usize>)) -> Self {
+        Rect {
+            rows: half_open(rows),
+            cols: half_open(cols),
+        }
+    }
+}
+
+/// `a..=b`를 `a..b + 1`로. 다 쓴 범위는 끝이 `Excluded`로 나오므로 빈 범위가 된다.
+fn half_open(range: RangeInclusive<usize>) -> Range<usize> {
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end
+            .checked_add(1)
+            .unwrap_or_else(|| panic!("range {range:?} overflows usize")),
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => unreachable!("RangeInclusive always has an end"),
+    };
+    *range.start()..end
+}
+
+/// 직사각형 합 질의
+pub trait RectSum {
+    type Value: Num;
+
+    fn rows(&self) -> usize;
+    fn cols(&self) -> usize;
+
+    /// `(0..rows, 0..cols)`의 합
+    fn prefix(&self, rows: usize, cols: usize) -> Self::Value;
+
+    /// 빈 직사각형이면 0
+    ///
+    /// # Panics
+    /// 직사각형이 격자를 벗어나면 panic한다.
+    fn sum(&self, rect: impl Into<Rect>) -> Self::Value {
+        let rect = rect.into();
+        if rect.is_empty() {
+            return Self::Value::ZERO;
+        }
+        let Rect { rows, cols } = rect;
+        assert!(
+            rows.end <= self.rows() && cols.end <= self.cols(),
+            "rect ({rows:?}, {cols:?}) is outside of {}x{} grid",
+            self.rows(),
+            self.cols()
+        );
+        (self.prefix(rows.end, cols.end) + self.prefix(rows.start, cols.start))
+            - (self.prefix(rows.start, cols.end) + self.prefix(rows.end, cols.start))
+    }
+}
+
+/// `sums[(i, j)]`는 `(0..i, 0..j)`의 합
+///
+/// ```text
+/// sums[i+1][j+1] = grid[i][j] + sums[i][j+1] + sums[i+1][j] - sums[i][j]
+/// ```
+#[derive(Clone, Debug)]
+pub struct PrefixSum2D<T> {
+    sums: Table2D<T>,
+}
+
+impl<T: Num> PrefixSum2D<T> {
+    pub fn from_fn(rows: usize, cols: usize, mut f: impl FnMut(usize, usize) -> T) -> Self {
+        let mut sums = Table2D::new(rows + 1, cols + 1, T::ZERO);
+        for i in 0..rows {
+            for j in 0..cols {
+                sums[(i + 1, j + 1)] =
+                    (f(i, j) + sums[(i, j + 1)] + sums[(i + 1, j)]) - sums[(i, j)];
+            }
+        }
+        Self { sums }
+    }
+
+    /// `Vec<Vec<T>>`, [`Table2D::iter_rows`], [`Grid::iter_rows`](crate::grid::Grid::iter_rows)
+    /// 무엇이든 행을 차례로 주면 된다.
+    ///
+    /// # Panics
+    /// 행마다 길이가 다르면 panic한다.
+    pub fn from_rows<R: AsRef<[T]>>(rows: impl IntoIterator<Item = R>) -> Self {
+        let rows = collect_rows(rows);
+        let cols = rows.first().map_or(0, |row| row.as_ref().len());
+        Self::from_fn(rows.len(), cols, |i, j| rows[i].as_ref()[j])
+    }
+}
+
+impl<T: Num> RectSum for PrefixSum2D<T> {
+    type Value = T;
+
+    fn rows(&self) -> usize {
+        self.sums.rows() - 1
+    }
+
+    fn cols(&self) -> usize {
+        self.sums.cols() - 1
+    }
+
+    fn prefix(&self, rows: usize, cols: usize) -> T {
+        self.sums[(rows, cols)]
+    }
+}
+
+/// 2차원 Fenwick tree(Binary Indexed Tree). 안에서는 1부터 센다.
+///
+/// `tree[(i, j)]`는 `(i - lowbit(i)..i, j - lowbit(j)..j)`의 합이다.
+#[derive(Clone, Debug)]
+pub struct Fenwick2D<T> {
+    tree: Table2D<T>,
+}
+
+const fn lowbit(i: usize) -> usize {
+    i & i.wrapping_neg()
+}
+
+impl<T: Num> Fenwick2D<T> {
+    /// 모두 0인 `rows`×`cols` 격자
+    pub fn new(rows: usize, cols: usize) -> Self {
+        Self {
+            tree: Table2D::new(rows + 1, cols + 1, T::ZERO),
+        }
+    }
+
+    /// 칸마다 [`Fenwick2D::add`]하지 않고 O(NM)에 만든다.
+    pub fn from_fn(rows: usize, cols: usize, mut f: impl FnMut(usize, usize) -> T) -> Self {
+        let mut tree = Table2D::new(rows + 1, cols + 1, T::ZERO);
+        for i in 1..=rows {
+            for j in 1..=cols {
+                tree[(i, j)] += f(i - 1, j - 1);
+            }
+        }
+        // 행마다 1차원 Fenwick tree로 만든 다음 열 방향으로 한 번 더 한다.
+        for i in 1..=rows {
+            for j in 1..=cols {
+                let parent = j + lowbit(j);
+                if parent <= cols {
+                    let value = tree[(i, j)];
+                    tree[(i, parent)] += value;
+                }
+            }
+        }
+        for i in 1..=rows {
+            let parent = i + lowbit(i);
+            if parent <= rows {
+                for j in 1..=cols {
+                    let value = tree[(i, j)];
+                    tree[(parent, j)] += value;
+                }
+            }
+        }
+        Self { tree }
+    }
+
+    /// # Panics
+    /// 행마다 길이가 다르면 panic한다.
+    pub fn from_rows<R: AsRef<[T]>>(rows: impl IntoIterator<Item = R>) -> Self {
+        let rows = collect_rows(rows);
+        let cols = rows.first().map_or(0, |row| row.as_ref().len());
+        Self::from_fn(rows.len(), cols, |i, j| rows[i].as_ref()[j])
+    }
+
+    /// `(row, col)`에 `delta`를 더한다.
+    pub fn add(&mut self, row: usize, col: usize, delta: T) {
+        assert!(
+            row < self.rows() && col < self.cols(),
+            "({row}, {col}) is outside of {}x{} grid",
+            self.rows(),
+            self.cols()
+        );
+        let mut i = row + 1;
+        while i <= self.rows() {
+            let mut j = col + 1;
+            while j <= self.cols() {
+                self.tree[(i, j)] += delta;
+                j += lowbit(j);
+            }
+            i += lowbit(i);
+        }
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> T {
+        self.sum((row..=row, col..=col))
+    }
+}
+
+impl<T: Num> RectSum for Fenwick2D<T> {
+    type Value = T;
+
+    fn rows(&self) -> usize {
+        self.tree.rows() - 1
+    }
+
+    fn cols(&self) -> usize {
+        self.tree.cols() - 1
+    }
+
+    fn prefix(&self, rows: usize, cols: usize) -> T {
+        let mut sum = T::ZERO;
+        let mut i = rows;
+        while i > 0 {
+            let mut j = cols;
+            while j > 0 {
+                sum += self.tree[(i, j)];
+                j -= lowbit(j);
+            }
+            i -= lowbit(i);
+        }
+        sum
+    }
+}
+
+fn collect_rows<T, R: AsRef<[T]>>(rows: impl IntoIterator<Item = R>) -> Vec<R> {
+    let rows: Vec<R> = rows.into_iter().collect();
+    let cols = rows.first().map_or(0, |row| row.as_ref().len());
+    assert!(
+        rows.iter().all(|row| row.as_ref().len() == cols),
+        "rows have different lengths"
+    );
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    use super::*;
+
+    fn brute_force(grid: &[Vec<i64>], rect: &Rect) -> i64 {
+        grid[rect.rows.clone()]
+            .iter()
+            .map(|row| row[rect.cols.clone()].iter().sum::<i64>())
+            .sum()
+    }
+
+    fn random_rect(rng: &mut StdRng, rows: usize, cols: usize) -> Rect {
+        let (r1, r2) = (rng.gen_range(0..=rows), rng.gen_range(0..=rows));
+        let (c1, c2) = (rng.gen_range(0..=cols), rng.gen_range(0..=cols));
+        (r1.min(r2)..r1.max(r2), c1.min(c2)..c1.max(c2)).into()
+    }
+
+    #[test]
+    fn inclusive_and_exclusive() {
+        assert_eq!(Rect::from((1..3, 0..2)), Rect::from((1..=2, 0..=1)));
+        assert!(Rect::from((1..1, 0..2)).is_empty());
+
+        let mut exhausted = 0..=1;
+        exhausted.by_ref().for_each(drop);
+        assert!(Rect::from((exhausted, 0..=1)).is_empty());
+
+        let sums = PrefixSum2D::from_fn(3, 3, |i, j| (i * 3 + j) as u32);
+        assert_eq!(36, sums.sum((0..3, 0..3)));
+        assert_eq!(4, sums.sum((1..=1, 1..=1)));
+        assert_eq!(0, sums.sum((2..2, 0..3)));
+        assert_eq!(3 + 4 + 6 + 7, sums.sum((1..=2, 0..=1)));
+    }
+
+    #[test]
+    #[should_panic(expected = "overflows usize")]
+    fn inclusive_end_overflows() {
+        PrefixSum2D::from_rows(&[vec![1]]).sum((0..=usize::MAX, 0..=0));
+    }
+
+    #[test]
+    #[should_panic(expected = "rect (0..3, 1..2) is outside of 2x2 grid")]
+    fn outside() {
+        PrefixSum2D::from_rows(&[vec![1, 2], vec![3, 4]]).sum((0..=2, 1..=1));
+    }
+
+    #[test]
+    fn prefix_sum_same_as_brute_force() {
+        let mut rng = StdRng::seed_from_u64(50);
+        for _ in 0..100 {
+            let (rows, cols) = (rng.gen_range(0..=8), rng.gen_range(0..=8));
+            let grid: Vec<Vec<i64>> = (0..rows)
+                .map(|_| (0..cols).map(|_| rng.gen_range(-100..=100)).collect())
+                .collect();
+            let sums = PrefixSum2D::from_rows(&grid);
+            for _ in 0..20 {
+                let rect = random_rect(&mut rng, rows, cols);
+                assert_eq!(
+                    brute_force(&grid, &rect),
+                    sums.sum(rect.clone()),
+                    "{rect:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn fenwick_same_as_brute_force() {
+        let mut rng = StdRng::seed_from_u64(51);
+        for _ in 0..100 {
+            let (rows, cols) = (rng.gen_range(1..=8), rng.gen_range(1..=8));
+            let mut grid: Vec<Vec<i64>> = (0..rows)
+                .map(|_| (0..cols).map(|_| rng.gen_range(-100..=100)).collect())
+                .collect();
+            let mut tree = Fenwick2D::from_rows(&grid);
+            for _ in 0..30 {
+                if rng.gen() {
+                    let (i, j) = (rng.gen_range(0..rows), rng.gen_range(0..cols));
+                    let delta = rng.gen_range(-100..=100);
+                    grid[i][j] += delta;
+                    tree.add(i, j, delta);
+                    assert_eq!(grid[i][j], tree.get(i, j));
+                } else {
+                    let rect = random_rect(&mut rng, rows, cols);
+                    assert_eq!(
+                        brute_force(&grid, &rect),
+                        tree.sum(rect.clone()),
+                        "{rect:?}"
+                    );
+                }
+            }
+
+            let mut added = Fenwick2D::new(rows, cols);
+            for (i, row) in grid.iter().enumerate() {
+                for (j, &value) in row.iter().enumerate() {
+                    added.add(i, j, value);
+                }
+            }
+            assert_eq!(added.tree, tree.tree);
+        }
+    }
+
+    #[test]
+    fn from_any_grid() {
+        let table = Table2D::from_fn(2, 3, |i, j| (i + j) as i64);
+        let from_table = PrefixSum2D::from_rows(table.iter_rows());
+        let from_vec = PrefixSum2D::from_rows(vec![vec![0, 1, 2], vec![1, 2, 3]]);
+        assert_eq!(from_vec.sum((0..2, 0..3)), from_table.sum((0..2, 0..3)));
+        assert_eq!(9, from_table.sum((0..2, 0..3)));
+        assert_eq!((2, 3), (from_table.rows(), from_table.cols()));
+    }
+}
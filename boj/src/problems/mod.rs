@@ -0,0 +1,89 @@
+//! 문제별 풀이. 모듈 이름은 `p<문제 번호>`이고, 각 모듈의 `Solution`이
+//! [`Solver`](crate::solver::Solver)를 구현한다.
+//!
+//! 새 문제를 추가할 때는 모듈을 선언하고 아래 `problems!` 목록에 한 줄 넣은 뒤,
+//! `src/bin/<문제 번호>.rs`에 `Solution::main()`을 부르는 `main`을 둔다.
+
+pub mod p1003;
+pub mod p10950;
+pub mod p10951;
+pub mod p10952;
+pub mod p10953;
+pub mod p10989;
+pub mod p11021;
+pub mod p11022;
+pub mod p11404;
+pub mod p11660;
+pub mod p11780;
+pub mod p14503;
+pub mod p15686;
+pub mod p15992;
+pub mod p2558;
+pub mod p27447;
+pub mod p2932;
+pub mod p3135;
+
+use std::io::{BufRead, Write};
+
+use crate::solver::{self, Solver};
+
+/// 트레이트 객체로 부를 수 있도록 `Solver::solve`를 구체 타입으로 고정한 함수
+pub type Entry = fn(&mut dyn BufRead, &mut dyn Write) -> solver::Result;
+
+macro_rules! problems {
+    ($($id:literal => $module:ident),* $(,)?) => {
+        /// `(문제 번호, 풀이)` 목록. 문제 번호 순으로 정렬되어 있다.
+        pub const PROBLEMS: &[(&str, Entry)] = &[
+            $(($id, |input, output| $module::Solution::solve(input, output)),)*
+        ];
+    };
+}
+
+problems! {
+    "1003" => p1003,
+    "2558" => p2558,
+    "2932" => p2932,
+    "3135" => p3135,
+    "10950" => p10950,
+    "10951" => p10951,
+    "10952" => p10952,
+    "10953" => p10953,
+    "10989" => p10989,
+    "11021" => p11021,
+    "11022" => p11022,
+    "11404" => p11404,
+    "11660" => p11660,
+    "11780" => p11780,
+    "14503" => p14503,
+    "15686" => p15686,
+    "15992" => p15992,
+    "27447" => p27447,
+}
+
+/// 문제 번호로 풀이를 찾는다.
+pub fn find(problem: &str) -> Option<Entry> {
+    PROBLEMS
+        .iter()
+        .find(|(id, _)| *id == problem)
+        .map(|&(_, entry)| entry)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sorted_and_unique() {
+        let ids: Vec<u32> = PROBLEMS.iter().map(|(id, _)| id.parse().unwrap()).collect();
+        assert!(ids.windows(2).all(|w| w[0] < w[1]), "{ids:?}");
+    }
+
+    #[test]
+    fn find_and_solve() {
+        let solve = find("2558").unwrap();
+        let mut output = vec![];
+        solve(&mut "1\n2\n".as_bytes(), &mut output).unwrap();
+        assert_eq!(b"3\n", &output[..]);
+        assert!(find("0").is_none());
+    }
+}
@@ -0,0 +1,50 @@
+//! <https://boj.kr/1003>
+
+use std::io::{BufRead, Write};
+
+use crate::{
+    outln,
+    output::Output,
+    scanner::Scanner,
+    solver::{self, Solver},
+    table::Table2D,
+};
+
+const MAX: usize = 40;
+
+/// fib(0)과 fib(1)이 호출된 횟수를 리턴. `dp`의 `n`번째 행에 `[fib(0) 횟수, fib(1) 횟수]`를 메모한다.
+fn solution_recur(dp: &mut Table2D<i32>, n: usize) -> [i32; 2] {
+    match n {
+        0 => [1, 0],
+        1 => [0, 1],
+        other if other <= MAX => {
+            if dp.row(other) == [0, 0] {
+                let twice_before = solution_recur(dp, n - 2);
+                let first_before = solution_recur(dp, n - 1);
+                dp[(other, 0)] = twice_before[0] + first_before[0];
+                dp[(other, 1)] = twice_before[1] + first_before[1];
+            }
+            [dp[(other, 0)], dp[(other, 1)]]
+        }
+        _ => panic!("bound error"),
+    }
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    fn solve<R: BufRead, W: Write>(input: R, output: W) -> solver::Result {
+        let mut sc = Scanner::new(input);
+        let mut out = Output::new(output);
+        let mut dp = Table2D::new(MAX + 1, 2, 0);
+        let t: usize = sc.next()?;
+        for _ in 0..t {
+            let n = sc.next()?;
+            let submit = solution_recur(&mut dp, n);
+            outln!(out; submit[0], submit[1])?;
+        }
+
+        out.flush()?;
+        Ok(())
+    }
+}
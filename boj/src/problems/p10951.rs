@@ -0,0 +1,26 @@
+//! <https://boj.kr/10951>
+
+use std::io::{BufRead, Write};
+
+use crate::{
+    outln,
+    output::Output,
+    scanner::Scanner,
+    solver::{self, Solver},
+};
+
+pub struct Solution;
+
+impl Solver for Solution {
+    fn solve<R: BufRead, W: Write>(input: R, output: W) -> solver::Result {
+        let mut sc = Scanner::new(input);
+        let mut out = Output::new(output);
+        while let Some(a) = sc.try_next::<i32>()? {
+            let b: i32 = sc.next()?;
+            outln!(out; a + b)?;
+        }
+
+        out.flush()?;
+        Ok(())
+    }
+}
@@ -0,0 +1,31 @@
+//! <https://boj.kr/10952>
+
+use std::io::{BufRead, Write};
+
+use crate::{
+    outln,
+    output::Output,
+    scanner::Scanner,
+    solver::{self, Solver},
+};
+
+pub struct Solution;
+
+impl Solver for Solution {
+    fn solve<R: BufRead, W: Write>(input: R, output: W) -> solver::Result {
+        let mut sc = Scanner::new(input);
+        let mut out = Output::new(output);
+        while let Some(a) = sc.try_next::<i32>()? {
+            let b: i32 = sc.next()?;
+            let case = a + b;
+            if case == 0 {
+                break;
+            } else {
+                outln!(out; case)?;
+            }
+        }
+
+        out.flush()?;
+        Ok(())
+    }
+}
@@ -0,0 +1,33 @@
+//! <https://boj.kr/10953>
+
+use std::io::{BufRead, Write};
+
+use crate::{
+    outln,
+    output::Output,
+    scanner::Scanner,
+    solver::{self, Solver},
+};
+
+pub struct Solution;
+
+impl Solver for Solution {
+    fn solve<R: BufRead, W: Write>(input: R, output: W) -> solver::Result {
+        let mut sc = Scanner::new(input);
+        let mut out = Output::new(output);
+        let t: i32 = sc.next()?;
+
+        for _ in 0..t {
+            // `1,2`처럼 쉼표로 구분되어 있으므로 토큰 하나를 직접 나눈다.
+            let case = sc
+                .next::<String>()?
+                .split(',')
+                .map(|e| e.parse::<i32>())
+                .sum::<Result<i32, _>>()?;
+            outln!(out; case)?;
+        }
+
+        out.flush()?;
+        Ok(())
+    }
+}
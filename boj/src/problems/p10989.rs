@@ -0,0 +1,38 @@
+//! <https://boj.kr/10989>
+
+use std::io::{BufRead, Write};
+
+use crate::{
+    outln,
+    output::Output,
+    scanner::Scanner,
+    solver::{self, Solver},
+};
+
+const MAX_V: usize = 10_000;
+type ElemType = u32;
+
+pub struct Solution;
+
+impl Solver for Solution {
+    fn solve<R: BufRead, W: Write>(input: R, output: W) -> solver::Result {
+        let mut sc = Scanner::new(input);
+        let mut out = Output::new(output);
+        let n: usize = sc.next()?;
+
+        let mut num_list = [0 as ElemType; MAX_V + 1];
+
+        for _ in 0..n {
+            num_list[sc.next::<usize>()?] += 1;
+        }
+
+        for (i, el) in num_list.into_iter().enumerate().filter(|(_, el)| *el > 0) {
+            for _ in 0..el {
+                outln!(out; i)?;
+            }
+        }
+
+        out.flush()?;
+        Ok(())
+    }
+}
@@ -0,0 +1,28 @@
+//! <https://boj.kr/11021>
+
+use std::io::{BufRead, Write};
+
+use crate::{
+    outln,
+    output::Output,
+    scanner::Scanner,
+    solver::{self, Solver},
+};
+
+pub struct Solution;
+
+impl Solver for Solution {
+    fn solve<R: BufRead, W: Write>(input: R, output: W) -> solver::Result {
+        let mut sc = Scanner::new(input);
+        let mut out = Output::new(output);
+        let t: i32 = sc.next()?;
+
+        for tc in 1..=t {
+            let (a, b): (i32, i32) = (sc.next()?, sc.next()?);
+            outln!(out, "Case #{}: {}", tc, a + b)?;
+        }
+
+        out.flush()?;
+        Ok(())
+    }
+}
@@ -0,0 +1,47 @@
+//! <https://boj.kr/11404>
+
+use std::io::{BufRead, Write};
+
+use crate::{
+    graph::{Format, Graph},
+    output::Output,
+    scanner::Scanner,
+    solver::{self, Solver},
+};
+
+pub struct Solution;
+
+impl Solver for Solution {
+    fn solve<R: BufRead, W: Write>(input: R, output: W) -> solver::Result {
+        solve_with(input, output, Format::Distances)
+    }
+}
+
+/// 버스 노선을 읽어 모든 쌍 최단 경로를 `format`으로 쓴다. [11780](super::p11780)도 같은
+/// 입력을 받고 출력 형식만 다르다.
+pub(crate) fn solve_with<R: BufRead, W: Write>(
+    input: R,
+    output: W,
+    format: Format,
+) -> solver::Result {
+    let mut sc = Scanner::new(input);
+    let mut out = Output::new(output);
+
+    let graph = read_graph(&mut sc)?;
+    graph.floyd_warshall()?.write(&mut out, format)?;
+    out.flush()?;
+    Ok(())
+}
+
+fn read_graph<R: BufRead>(sc: &mut Scanner<R>) -> solver::Result<Graph<i64>> {
+    let n: usize = sc.next()?;
+    let m: usize = sc.next()?;
+
+    // 같은 도시 쌍 사이의 버스가 여러 대일 수 있는데, 행렬은 가장 싼 것만 남긴다.
+    let mut graph = Graph::matrix(n);
+    for _ in 0..m {
+        let (from, to, cost): (usize, usize, i64) = (sc.next()?, sc.next()?, sc.next()?);
+        graph.add_edge(from - 1, to - 1, cost);
+    }
+    Ok(graph)
+}
@@ -0,0 +1,222 @@
+//! <https://boj.kr/11660>
+
+use std::{
+    fmt::{self, Display},
+    io::{BufRead, Write},
+};
+
+use rand::{rngs::StdRng, Rng};
+
+use crate::{
+    outln,
+    output::Output,
+    prefix_sum::{Fenwick2D, PrefixSum2D, RectSum},
+    scanner::Scanner,
+    solver::{self, Solver},
+    stress::Case,
+};
+
+type Value = i32;
+
+/// 표를 읽어 `build`로 만든 [`RectSum`]으로 질의마다 한 줄씩 답한다. 좌표는 문제처럼
+/// 1부터 센다.
+fn solve_with<R, W, S>(
+    input: R,
+    output: W,
+    build: impl FnOnce(&[Vec<Value>]) -> S,
+) -> solver::Result
+where
+    R: BufRead,
+    W: Write,
+    S: RectSum<Value = Value>,
+{
+    let mut sc = Scanner::new(input);
+    let mut out = Output::new(output);
+    let (n, m): (usize, usize) = (sc.next()?, sc.next()?);
+    let grid: Vec<Vec<Value>> = sc.next_grid(n, n)?;
+
+    let sums = build(&grid);
+    for _ in 0..m {
+        let [x1, y1, x2, y2]: [usize; 4] = sc.next_vec(4)?.try_into().unwrap();
+        outln!(out; sums.sum((x1 - 1..=x2 - 1, y1 - 1..=y2 - 1)))?;
+    }
+
+    out.flush()?;
+    Ok(())
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    fn solve<R: BufRead, W: Write>(input: R, output: W) -> solver::Result {
+        solve_with(input, output, |grid| PrefixSum2D::from_rows(grid))
+    }
+}
+
+/// [`Solution`]과 같지만 [`Fenwick2D`]로 답한다.
+pub struct WithFenwick;
+
+impl Solver for WithFenwick {
+    fn solve<R: BufRead, W: Write>(input: R, output: W) -> solver::Result {
+        solve_with(input, output, |grid| Fenwick2D::from_rows(grid))
+    }
+}
+
+/// stress test용 무작위 입력. 좌표는 문제처럼 1부터 센다.
+#[derive(Clone, Debug)]
+pub struct Input {
+    grid: Vec<Vec<Value>>,
+    /// `[x1, y1, x2, y2]`, `x1 <= x2`이고 `y1 <= y2`
+    queries: Vec<[usize; 4]>,
+}
+
+impl Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} {}", self.grid.len(), self.queries.len())?;
+        for row in &self.grid {
+            let row: Vec<_> = row.iter().map(Value::to_string).collect();
+            writeln!(f, "{}", row.join(" "))?;
+        }
+        for [x1, y1, x2, y2] in &self.queries {
+            writeln!(f, "{x1} {y1} {x2} {y2}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Case for Input {
+    fn generate(rng: &mut StdRng) -> Self {
+        let n = rng.gen_range(1..=8);
+        let grid = (0..n)
+            .map(|_| (0..n).map(|_| rng.gen_range(1..=1000)).collect())
+            .collect();
+        let queries = (0..rng.gen_range(1..=10))
+            .map(|_| {
+                let (x1, x2) = (rng.gen_range(1..=n), rng.gen_range(1..=n));
+                let (y1, y2) = (rng.gen_range(1..=n), rng.gen_range(1..=n));
+                [x1.min(x2), y1.min(y2), x1.max(x2), y1.max(y2)]
+            })
+            .collect();
+        Self { grid, queries }
+    }
+
+    fn shrink(&self) -> Vec<Self> {
+        let mut candidates = vec![];
+        // 질의를 하나 뺀다.
+        for i in 0..self.queries.len() {
+            if self.queries.len() > 1 {
+                let mut smaller = self.clone();
+                smaller.queries.remove(i);
+                candidates.push(smaller);
+            }
+        }
+        // 질의 범위를 한 칸 줄인다.
+        for (i, &[x1, y1, x2, y2]) in self.queries.iter().enumerate() {
+            let shrunk = [
+                [x1, y1, x2 - 1, y2],
+                [x1, y1, x2, y2 - 1],
+                [x1 + 1, y1, x2, y2],
+                [x1, y1 + 1, x2, y2],
+            ];
+            for query in shrunk {
+                if query[0] <= query[2] && query[1] <= query[3] {
+                    let mut smaller = self.clone();
+                    smaller.queries[i] = query;
+                    candidates.push(smaller);
+                }
+            }
+        }
+        // 마지막 행과 열을 뺀다. 벗어나는 질의도 함께 뺀다.
+        let n = self.grid.len();
+        if n > 1 {
+            let mut smaller = self.clone();
+            smaller.grid.pop();
+            smaller.grid.iter_mut().for_each(|row| {
+                row.pop();
+            });
+            smaller.queries.retain(|q| q[2] < n && q[3] < n);
+            if !smaller.queries.is_empty() {
+                candidates.push(smaller);
+            }
+        }
+        // 첫 행과 열을 빼고 질의를 한 칸씩 당긴다.
+        if n > 1 {
+            let mut smaller = self.clone();
+            smaller.grid.remove(0);
+            smaller.grid.iter_mut().for_each(|row| {
+                row.remove(0);
+            });
+            smaller.queries.retain(|q| q[0] > 1 && q[1] > 1);
+            smaller.queries.iter_mut().flatten().for_each(|x| *x -= 1);
+            if !smaller.queries.is_empty() {
+                candidates.push(smaller);
+            }
+        }
+        // 값을 1로 바꾼다.
+        for i in 0..n {
+            for j in 0..n {
+                if self.grid[i][j] > 1 {
+                    let mut smaller = self.clone();
+                    smaller.grid[i][j] = 1;
+                    candidates.push(smaller);
+                }
+            }
+        }
+        candidates
+    }
+}
+
+/// 질의마다 직사각형 안의 값을 전부 더한다.
+pub struct BruteForce;
+
+impl Solver for BruteForce {
+    fn solve<R: BufRead, W: Write>(input: R, output: W) -> solver::Result {
+        let mut sc = Scanner::new(input);
+        let mut out = Output::new(output);
+        let (n, m): (usize, usize) = (sc.next()?, sc.next()?);
+        let grid: Vec<Vec<i64>> = sc.next_grid(n, n)?;
+        for _ in 0..m {
+            let [x1, y1, x2, y2]: [usize; 4] = sc.next_vec(4)?.try_into().unwrap();
+            let sum: i64 = grid[x1 - 1..x2]
+                .iter()
+                .map(|row| row[y1 - 1..y2].iter().sum::<i64>())
+                .sum();
+            outln!(out; sum)?;
+        }
+        out.flush()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    const MAX_N: usize = 1024;
+    const MAX_M: usize = 100_000;
+    const MAX_RNG: i32 = 1000;
+
+    use super::*;
+    use crate::stress;
+    use rand::{self, Rng};
+
+    #[test]
+    fn timeout() {
+        let mut r = rand::thread_rng();
+        let sums = PrefixSum2D::from_fn(MAX_N, MAX_N, |_, _| r.gen_range(1..=MAX_RNG));
+
+        for _ in 0..MAX_M {
+            let (x1, x2) = (r.gen_range(0..MAX_N), r.gen_range(0..MAX_N));
+            let (y1, y2) = (r.gen_range(0..MAX_N), r.gen_range(0..MAX_N));
+            sums.sum((x1.min(x2)..=x1.max(x2), y1.min(y2)..=y1.max(y2)));
+        }
+    }
+
+    #[test]
+    fn stress() {
+        stress::check::<Input, Solution, BruteForce>();
+    }
+
+    #[test]
+    fn stress_fenwick() {
+        stress::check::<Input, WithFenwick, BruteForce>();
+    }
+}
@@ -0,0 +1,17 @@
+//! <https://boj.kr/11780>
+
+use std::io::{BufRead, Write};
+
+use super::p11404;
+use crate::{
+    graph::Format,
+    solver::{self, Solver},
+};
+
+pub struct Solution;
+
+impl Solver for Solution {
+    fn solve<R: BufRead, W: Write>(input: R, output: W) -> solver::Result {
+        p11404::solve_with(input, output, Format::Paths)
+    }
+}
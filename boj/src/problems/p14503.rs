@@ -0,0 +1,502 @@
+//! 시뮬구현 만큼은 러스트로 풀어보고 싶어!
+//!
+//! <https://boj.kr/14503>
+use std::{
+    error::Error,
+    fmt::{self, Display, Write as _},
+    io::{BufRead, Write},
+    iter,
+};
+
+use crate::{
+    grid::{Agent, Direction as _, Grid},
+    outln,
+    output::Output,
+    scanner::Scanner,
+    solver::{self, Solver},
+};
+
+pub use crate::grid::{Dir4, Position};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RoomState {
+    Wall,
+    Dirty,
+    Clean,
+}
+
+/// 로봇이 한 걸음에 한 일
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RobotEvent {
+    Cleaned(Position),
+    /// 반시계 방향으로 돌아 이 방향을 보게 됐다.
+    Rotated(Dir4),
+    MovedForward(Position),
+    MovedBackward(Position),
+    /// 뒤가 막혀 멈췄다.
+    Halted(Position),
+}
+
+#[derive(Debug)]
+struct Robot {
+    pos: Position,
+    dir: Dir4,
+    /// 방금 돌았는지. 돈 다음에만 앞 칸을 보고 전진한다.
+    turned: bool,
+}
+
+pub struct Room {
+    robot: Robot,
+    map: Grid<RoomState>,
+}
+
+/// 크기를 정해 두고 벽, 청소할 칸, 로봇을 놓은 뒤 [`RoomBuilder::build`]로 [`Room`]을 만든다.
+/// 따로 정하지 않은 칸은 벽이다.
+#[derive(Debug)]
+pub struct RoomBuilder {
+    height: usize,
+    width: usize,
+    robot_pos: Position,
+    robot_dir: Dir4,
+    walls: Vec<Position>,
+    dirts: Vec<Position>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BuildError {
+    WallOutOfBounds(Position),
+    DirtOutOfBounds(Position),
+    RobotOutOfBounds(Position),
+    RobotOnWall(Position),
+}
+
+impl Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BuildError::WallOutOfBounds(pos) => write!(f, "wall at {pos} is outside the room"),
+            BuildError::DirtOutOfBounds(pos) => write!(f, "dirt at {pos} is outside the room"),
+            BuildError::RobotOutOfBounds(pos) => write!(f, "robot at {pos} is outside the room"),
+            BuildError::RobotOnWall(pos) => write!(f, "robot at {pos} is on a wall"),
+        }
+    }
+}
+
+impl Error for BuildError {}
+
+impl RoomBuilder {
+    pub fn new(height: usize, width: usize) -> Self {
+        Self {
+            height,
+            width,
+            robot_pos: Position(0, 0),
+            robot_dir: 0.into(),
+            walls: vec![],
+            dirts: vec![],
+        }
+    }
+    pub fn set_robot_pos(&mut self, pos: Position) -> &mut Self {
+        self.robot_pos = pos;
+        self
+    }
+    pub fn set_robot_dir(&mut self, dir: Dir4) -> &mut Self {
+        self.robot_dir = dir;
+        self
+    }
+    pub fn add_wall(&mut self, pos: Position) -> &mut Self {
+        self.walls.push(pos);
+        self
+    }
+    pub fn add_dirt(&mut self, pos: Position) -> &mut Self {
+        self.dirts.push(pos);
+        self
+    }
+    /// 같은 칸에 벽과 청소할 곳이 모두 있으면 청소할 곳이 된다.
+    pub fn build(self) -> Result<Room, BuildError> {
+        let mut map = Grid::new(self.height, self.width, RoomState::Wall);
+        for e in self.walls {
+            *map.get_mut(e).ok_or(BuildError::WallOutOfBounds(e))? = RoomState::Wall;
+        }
+        for e in self.dirts {
+            *map.get_mut(e).ok_or(BuildError::DirtOutOfBounds(e))? = RoomState::Dirty;
+        }
+        match map.get(self.robot_pos) {
+            None => return Err(BuildError::RobotOutOfBounds(self.robot_pos)),
+            Some(RoomState::Wall) => return Err(BuildError::RobotOnWall(self.robot_pos)),
+            Some(_) => {}
+        }
+        Ok(Room {
+            robot: Robot {
+                pos: self.robot_pos,
+                dir: self.robot_dir,
+                turned: false,
+            },
+            map,
+        })
+    }
+}
+
+impl Robot {
+    fn upfront(&self) -> Position {
+        self.pos + self.dir
+    }
+    fn of_behind(&self) -> Position {
+        self.pos + self.dir.opposite()
+    }
+}
+
+impl Agent<RoomState> for Robot {
+    type Event = RobotEvent;
+
+    fn step(&mut self, map: &mut Grid<RoomState>) -> Option<RobotEvent> {
+        let here = map.get_mut(self.pos)?;
+        if *here == RoomState::Dirty {
+            // DO clean this floor
+            *here = RoomState::Clean;
+            return Some(RobotEvent::Cleaned(self.pos));
+        }
+
+        let is_dirty = |pos| map.get(pos) == Some(&RoomState::Dirty);
+        if map
+            .neighbors::<Dir4>(self.pos)
+            .any(|(_, pos)| is_dirty(pos))
+        {
+            // Wow, we find a new place to clean!!! Let's turn until it is upfront.
+            if self.turned && is_dirty(self.upfront()) {
+                self.pos = self.upfront();
+                self.turned = false;
+                return Some(RobotEvent::MovedForward(self.pos));
+            }
+            self.dir = self.dir.ccw();
+            self.turned = true;
+            return Some(RobotEvent::Rotated(self.dir));
+        }
+
+        // there were no dirty floors near to robot!!!
+        // the robot DO tries to move backward, unless there is a wall
+        match map.get(self.of_behind()) {
+            None | Some(RoomState::Wall) => None,
+            Some(_) => {
+                self.pos = self.of_behind();
+                self.turned = false;
+                Some(RobotEvent::MovedBackward(self.pos))
+            }
+        }
+    }
+}
+
+impl Room {
+    pub fn width(&self) -> usize {
+        self.map.cols()
+    }
+
+    pub fn height(&self) -> usize {
+        self.map.rows()
+    }
+
+    pub fn is_inside(&self, pos: Position) -> bool {
+        self.map.contains(pos)
+    }
+
+    /// 방 밖이면 `None`
+    pub fn get(&self, pos: Position) -> Option<RoomState> {
+        self.map.get(pos).copied()
+    }
+
+    /// 로봇을 한 걸음 움직인다. 멈춘 뒤로는 계속 [`RobotEvent::Halted`]를 돌려준다.
+    pub fn step(&mut self) -> RobotEvent {
+        self.robot
+            .step(&mut self.map)
+            .unwrap_or(RobotEvent::Halted(self.robot.pos))
+    }
+
+    /// 로봇이 멈출 때까지의 사건들. 마지막은 [`RobotEvent::Halted`]다.
+    pub fn events(&mut self) -> impl Iterator<Item = RobotEvent> + '_ {
+        let mut halted = false;
+        iter::from_fn(move || {
+            if halted {
+                return None;
+            }
+            let event = self.step();
+            halted = matches!(event, RobotEvent::Halted(_));
+            Some(event)
+        })
+    }
+
+    pub fn run_robot<T>(&mut self, mut on_clean: T)
+    where
+        T: FnMut(Position),
+    {
+        for event in self.events() {
+            if let RobotEvent::Cleaned(pos) = event {
+                on_clean(pos);
+            }
+        }
+    }
+}
+
+impl Display for RobotEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RobotEvent::Cleaned(pos) => write!(f, "cleaned {pos}"),
+            RobotEvent::Rotated(dir) => write!(f, "rotated to {dir:?}"),
+            RobotEvent::MovedForward(pos) => write!(f, "moved forward to {pos}"),
+            RobotEvent::MovedBackward(pos) => write!(f, "moved backward to {pos}"),
+            RobotEvent::Halted(pos) => write!(f, "halted at {pos}"),
+        }
+    }
+}
+
+/// 벽은 `#`, 청소할 곳은 `.`, 청소한 곳은 `*`, 로봇은 보는 방향에 따라 `^>v<`
+impl Display for Room {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.map.iter_rows().enumerate() {
+            for (j, cell) in row.iter().enumerate() {
+                let c = if self.robot.pos == Position(i as isize, j as isize) {
+                    match self.robot.dir {
+                        Dir4::North => '^',
+                        Dir4::East => '>',
+                        Dir4::South => 'v',
+                        Dir4::West => '<',
+                    }
+                } else {
+                    match cell {
+                        RoomState::Wall => '#',
+                        RoomState::Dirty => '.',
+                        RoomState::Clean => '*',
+                    }
+                };
+                f.write_char(c)?;
+            }
+            f.write_char('\n')?;
+        }
+        Ok(())
+    }
+}
+
+fn read_room<R: BufRead>(sc: &mut Scanner<R>) -> solver::Result<Room> {
+    let (n, m): (usize, usize) = (sc.next()?, sc.next()?);
+    let mut builder = RoomBuilder::new(n, m);
+
+    let (robot_y, robot_x, dir): (isize, isize, isize) = (sc.next()?, sc.next()?, sc.next()?);
+    builder.set_robot_pos(Position(robot_y, robot_x));
+    builder.set_robot_dir(dir.into());
+
+    for (i, row) in sc.next_grid::<u8>(n, m)?.into_iter().enumerate() {
+        for (j, elem) in row.into_iter().enumerate() {
+            match elem {
+                0 => builder.add_dirt(Position(i as isize, j as isize)),
+                _ => builder.add_wall(Position(i as isize, j as isize)),
+            };
+        }
+    }
+
+    Ok(builder.build()?)
+}
+
+/// 답은 `output`에 쓰고, 걸음마다 무슨 일이 있었는지와 방의 모습을 `trace`에 쓴다.
+/// `14503 --trace`가 부른다.
+pub fn trace<R: BufRead, W: Write, T: Write>(input: R, output: W, mut trace: T) -> solver::Result {
+    let mut sc = Scanner::new(input);
+    let mut out = Output::new(output);
+    let mut room = read_room(&mut sc)?;
+
+    writeln!(trace, "step 0: start\n{room}")?;
+    let mut count = 0;
+    for step in 1.. {
+        let event = room.step();
+        if let RobotEvent::Cleaned(_) = event {
+            count += 1;
+        }
+        writeln!(trace, "step {step}: {event}\n{room}")?;
+        if let RobotEvent::Halted(_) = event {
+            break;
+        }
+    }
+    trace.flush()?;
+
+    outln!(out; count)?;
+    out.flush()?;
+    Ok(())
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    fn solve<R: BufRead, W: Write>(input: R, output: W) -> solver::Result {
+        let mut sc = Scanner::new(input);
+        let mut out = Output::new(output);
+        let mut room = read_room(&mut sc)?;
+
+        let mut count = 0;
+        room.run_robot(|_pos| {
+            count += 1;
+        });
+
+        outln!(out; count)?;
+
+        out.flush()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn ccw() {
+        let d: Dir4 = 0.into();
+        assert_eq!(d, Dir4::North);
+        assert_eq!(d.ccw(), Dir4::West);
+        assert_eq!(d.ccw().ccw(), Dir4::South);
+        assert_eq!(d.ccw().ccw().ccw(), Dir4::East);
+    }
+
+    #[test]
+    fn backward() {
+        assert_eq!(-Dir4::North, Dir4::South);
+        assert_eq!(-Dir4::South, Dir4::North);
+        assert_eq!(-Dir4::East, Dir4::West);
+        assert_eq!(-Dir4::West, Dir4::East);
+    }
+
+    #[test]
+    fn into_direction() {
+        assert_eq!(Dir4::from(-4), Dir4::North);
+        assert_eq!(Dir4::from(-3), Dir4::East);
+        assert_eq!(Dir4::from(-2), Dir4::South);
+        assert_eq!(Dir4::from(-1), Dir4::West);
+        assert_eq!(Dir4::from(0), Dir4::North);
+        assert_eq!(Dir4::from(1), Dir4::East);
+        assert_eq!(Dir4::from(2), Dir4::South);
+        assert_eq!(Dir4::from(3), Dir4::West);
+        assert_eq!(Dir4::from(4), Dir4::North);
+    }
+
+    fn small_room() -> Room {
+        let mut sc = Scanner::new("3 4\n1 1 1\n1 1 1 1\n1 0 0 1\n1 1 1 1\n".as_bytes());
+        read_room(&mut sc).unwrap()
+    }
+
+    #[test]
+    fn events() {
+        use RobotEvent::*;
+
+        let mut room = small_room();
+        let events: Vec<_> = room.events().collect();
+        assert_eq!(
+            vec![
+                Cleaned(Position(1, 1)),
+                Rotated(Dir4::North),
+                Rotated(Dir4::West),
+                Rotated(Dir4::South),
+                Rotated(Dir4::East),
+                MovedForward(Position(1, 2)),
+                Cleaned(Position(1, 2)),
+                MovedBackward(Position(1, 1)),
+                Halted(Position(1, 1)),
+            ],
+            events
+        );
+        assert_eq!(Halted(Position(1, 1)), room.step());
+        assert_eq!(0, room.events().filter(|e| matches!(e, Cleaned(_))).count());
+    }
+
+    #[test]
+    fn render() {
+        let mut room = small_room();
+        let frame = room.to_string();
+        assert_eq!("####\n#>.#\n####\n", &frame[..15]);
+        room.step();
+        assert!(room.to_string().starts_with("####\n#>.#\n"));
+        room.events().for_each(drop);
+        assert!(room.to_string().starts_with("####\n#>*#\n####\n"));
+    }
+
+    #[test]
+    fn trace_dumps_every_frame() {
+        let mut output = vec![];
+        let mut frames = vec![];
+        let input = "3 4\n1 1 1\n1 1 1 1\n1 0 0 1\n1 1 1 1\n";
+        trace(input.as_bytes(), &mut output, &mut frames).unwrap();
+        assert_eq!(b"2\n", &output[..]);
+
+        let frames = String::from_utf8(frames).unwrap();
+        assert!(frames.starts_with("step 0: start\n####\n#>.#\n"));
+        assert_eq!(10, frames.matches("step ").count());
+        assert!(frames.contains("step 9: halted at (1, 1)\n####\n#>*#\n"));
+    }
+
+    fn builder() -> RoomBuilder {
+        let mut builder = RoomBuilder::new(2, 3);
+        builder
+            .add_wall(Position(0, 0))
+            .add_dirt(Position(0, 1))
+            .add_dirt(Position(1, 2))
+            .set_robot_pos(Position(0, 1));
+        builder
+    }
+
+    #[test]
+    fn build_sized_room() {
+        let room = builder().build().unwrap();
+        assert_eq!((2, 3), (room.height(), room.width()));
+        assert_eq!(Some(RoomState::Wall), room.get(Position(0, 0)));
+        assert_eq!(Some(RoomState::Dirty), room.get(Position(1, 2)));
+        // 따로 정하지 않은 칸은 벽
+        assert_eq!(Some(RoomState::Wall), room.get(Position(1, 0)));
+        assert!(room.is_inside(Position(1, 2)));
+        for outside in [
+            Position(-1, 0),
+            Position(0, -1),
+            Position(2, 0),
+            Position(0, 3),
+        ] {
+            assert!(!room.is_inside(outside));
+            assert_eq!(None, room.get(outside));
+        }
+    }
+
+    #[test]
+    fn build_errors() {
+        let mut wall = builder();
+        wall.add_wall(Position(2, 0));
+        assert_eq!(
+            Err(BuildError::WallOutOfBounds(Position(2, 0))),
+            wall.build().map(|_| ())
+        );
+
+        let mut dirt = builder();
+        dirt.add_dirt(Position(0, -1));
+        assert_eq!(
+            Err(BuildError::DirtOutOfBounds(Position(0, -1))),
+            dirt.build().map(|_| ())
+        );
+
+        let mut outside = builder();
+        outside.set_robot_pos(Position(0, 3));
+        assert_eq!(
+            Err(BuildError::RobotOutOfBounds(Position(0, 3))),
+            outside.build().map(|_| ())
+        );
+
+        let mut on_wall = builder();
+        on_wall.set_robot_pos(Position(0, 0));
+        assert_eq!(
+            Err(BuildError::RobotOnWall(Position(0, 0))),
+            on_wall.build().map(|_| ())
+        );
+        // 아무것도 놓지 않은 칸도 벽이다.
+        let mut on_default = builder();
+        on_default.set_robot_pos(Position(1, 1));
+        assert_eq!(
+            Err(BuildError::RobotOnWall(Position(1, 1))),
+            on_default.build().map(|_| ())
+        );
+    }
+
+    #[test]
+    fn invalid_input_is_an_error() {
+        let err = Solution::solve_str("3 3\n0 0 0\n1 1 1\n1 0 1\n1 1 1\n").unwrap_err();
+        assert_eq!("robot at (0, 0) is on a wall", err.to_string());
+    }
+}
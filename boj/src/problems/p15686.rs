@@ -0,0 +1,270 @@
+//! <https://boj.kr/15686>
+
+use std::{
+    fmt::{self, Display},
+    io::{BufRead, Write},
+    ops::Sub,
+};
+
+use rand::{rngs::StdRng, Rng};
+
+use crate::{
+    outln,
+    output::Output,
+    scanner::Scanner,
+    solver::{self, Solver},
+    stress::Case,
+};
+
+const EMPTY: u8 = 0;
+const HOUSE: u8 = 1;
+const CHICKEN: u8 = 2;
+/// 문제에서 치킨집은 최대 13개
+const MAX_CHICKENS: usize = 13;
+
+#[derive(Copy, Clone, Debug)]
+struct Point(i32, i32);
+#[derive(Copy, Clone, Debug)]
+struct Chicken {
+    pos: Point,
+    is_alive: bool,
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Point(self.0 - rhs.0, self.1 - rhs.1)
+    }
+}
+
+impl Point {
+    pub fn abs(self) -> i32 {
+        self.0.abs() + self.1.abs()
+    }
+}
+
+impl Chicken {
+    pub fn new(i: i32, j: i32) -> Self {
+        Self {
+            pos: Point(i, j),
+            is_alive: true,
+        }
+    }
+}
+
+/// sum of the least distances between houses and chickens which is alive
+fn chicken_dist(houses: &[Point], chickens: &[Chicken]) -> i32 {
+    let mut sum = 0;
+    for &house in houses.iter() {
+        let mut min = i32::MAX;
+        for chicken in chickens.iter().filter(|e| e.is_alive).map(|e| e.pos) {
+            min = min.min((house - chicken).abs());
+        }
+        sum += min;
+    }
+    sum
+}
+
+fn solution_recur(houses: &[Point], chickens: &mut [Chicken], m: i32, start_from: usize) -> i32 {
+    if chickens.iter().filter(|e| e.is_alive).count() <= m as usize {
+        return chicken_dist(houses, chickens);
+    }
+    let mut local_best = i32::MAX;
+
+    for i in start_from..chickens.len() {
+        chickens[i].is_alive = false;
+        local_best = local_best.min(solution_recur(houses, chickens, m, i + 1));
+        chickens[i].is_alive = true;
+    }
+
+    local_best
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    fn solve<R: BufRead, W: Write>(input: R, output: W) -> solver::Result {
+        let mut sc = Scanner::new(input);
+        let mut out = Output::new(output);
+        let (n, m): (usize, i32) = (sc.next()?, sc.next()?);
+        let mut houses: Vec<Point> = vec![];
+        let mut chickens: Vec<Chicken> = vec![];
+
+        for (row, i) in sc.next_grid::<u8>(n, n)?.into_iter().zip(0..) {
+            for (elem, j) in row.iter().zip(0..) {
+                match *elem {
+                    HOUSE => houses.push(Point(i, j)),
+                    CHICKEN => chickens.push(Chicken::new(i, j)),
+                    _ => continue,
+                }
+            }
+        }
+
+        let submit = solution_recur(&houses, &mut chickens, m, 0);
+
+        outln!(out; submit)?;
+
+        out.flush()?;
+        Ok(())
+    }
+}
+
+/// stress test용 무작위 입력
+#[derive(Clone, Debug)]
+pub struct Input {
+    m: usize,
+    grid: Vec<Vec<u8>>,
+}
+
+impl Input {
+    fn count(&self, cell: u8) -> usize {
+        self.grid.iter().flatten().filter(|&&c| c == cell).count()
+    }
+
+    /// 집이 하나 이상 있고, 치킨집이 `m`개 이상 [`MAX_CHICKENS`]개 이하인지
+    fn is_valid(&self) -> bool {
+        let chickens = self.count(CHICKEN);
+        self.grid.len() >= 2
+            && self.count(HOUSE) >= 1
+            && (self.m..=MAX_CHICKENS).contains(&chickens)
+    }
+}
+
+impl Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} {}", self.grid.len(), self.m)?;
+        for row in &self.grid {
+            let row: Vec<_> = row.iter().map(u8::to_string).collect();
+            writeln!(f, "{}", row.join(" "))?;
+        }
+        Ok(())
+    }
+}
+
+impl Case for Input {
+    fn generate(rng: &mut StdRng) -> Self {
+        let n = rng.gen_range(2..=7);
+        let mut grid: Vec<Vec<u8>> = (0..n)
+            .map(|_| {
+                (0..n)
+                    .map(|_| match rng.gen_range(0..20) {
+                        0..=4 => HOUSE,
+                        5..=7 => CHICKEN,
+                        _ => EMPTY,
+                    })
+                    .collect()
+            })
+            .collect();
+        // 집과 치킨집이 적어도 하나씩 있도록 서로 다른 두 칸을 정한다.
+        let house = rng.gen_range(0..n * n);
+        let chicken = (house + rng.gen_range(1..n * n)) % (n * n);
+        grid[house / n][house % n] = HOUSE;
+        grid[chicken / n][chicken % n] = CHICKEN;
+        // 치킨집이 너무 많으면 앞에서부터 지운다.
+        let mut chickens = grid.iter().flatten().filter(|&&c| c == CHICKEN).count();
+        for (i, cell) in grid.iter_mut().flatten().enumerate() {
+            if chickens > MAX_CHICKENS && *cell == CHICKEN && i != chicken {
+                *cell = EMPTY;
+                chickens -= 1;
+            }
+        }
+        Self {
+            m: rng.gen_range(1..=chickens),
+            grid,
+        }
+    }
+
+    fn shrink(&self) -> Vec<Self> {
+        let n = self.grid.len();
+        let mut candidates = vec![];
+        if self.m > 1 {
+            candidates.push(Self {
+                m: self.m - 1,
+                grid: self.grid.clone(),
+            });
+        }
+        // 마지막 또는 첫 행과 열을 뺀다.
+        for first in [false, true] {
+            let mut smaller = self.clone();
+            let idx = if first { 0 } else { n - 1 };
+            smaller.grid.remove(idx);
+            smaller.grid.iter_mut().for_each(|row| {
+                row.remove(idx);
+            });
+            candidates.push(smaller);
+        }
+        // 집이나 치킨집을 하나 지운다.
+        for (i, j) in (0..n).flat_map(|i| (0..n).map(move |j| (i, j))) {
+            if self.grid[i][j] != EMPTY {
+                let mut smaller = self.clone();
+                smaller.grid[i][j] = EMPTY;
+                candidates.push(smaller);
+            }
+        }
+        candidates.retain(Input::is_valid);
+        candidates
+    }
+}
+
+/// 치킨집 `m`개를 고르는 모든 경우를 비트마스크로 확인한다.
+pub struct BruteForce;
+
+impl Solver for BruteForce {
+    fn solve<R: BufRead, W: Write>(input: R, output: W) -> solver::Result {
+        let mut sc = Scanner::new(input);
+        let mut out = Output::new(output);
+        let (n, m): (usize, u32) = (sc.next()?, sc.next()?);
+        let grid: Vec<Vec<u8>> = sc.next_grid(n, n)?;
+        let cells = |kind| {
+            let grid = &grid;
+            (0..n).flat_map(move |i| {
+                (0..n)
+                    .filter(move |&j| grid[i][j] == kind)
+                    .map(move |j| (i, j))
+            })
+        };
+        let houses: Vec<_> = cells(HOUSE).collect();
+        let chickens: Vec<_> = cells(CHICKEN).collect();
+
+        let best = (0u32..1 << chickens.len())
+            .filter(|mask| mask.count_ones() == m)
+            .map(|mask| {
+                houses
+                    .iter()
+                    .map(|&(hi, hj)| {
+                        (0..chickens.len())
+                            .filter(|&c| mask >> c & 1 == 1)
+                            .map(|c| hi.abs_diff(chickens[c].0) + hj.abs_diff(chickens[c].1))
+                            .min()
+                            .unwrap()
+                    })
+                    .sum::<usize>()
+            })
+            .min()
+            .unwrap();
+        outln!(out; best)?;
+        out.flush()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stress;
+
+    #[test]
+    fn generated_inputs_are_valid() {
+        use rand::SeedableRng;
+        for seed in 0..500 {
+            let input = Input::generate(&mut StdRng::seed_from_u64(seed));
+            assert!(input.is_valid(), "{input}");
+        }
+    }
+
+    #[test]
+    fn stress() {
+        stress::check::<Input, Solution, BruteForce>();
+    }
+}
@@ -0,0 +1,60 @@
+//! <https://boj.kr/15992>
+
+use std::io::{BufRead, Write};
+
+use crate::{
+    outln,
+    output::Output,
+    scanner::Scanner,
+    solver::{self, Solver},
+    table::Table2D,
+};
+
+const MAX_N: usize = 1000;
+const MODULO: u32 = 1_000_000_009;
+type ArrT = Table2D<Option<u32>>;
+
+fn get(arr: &mut ArrT, n: i32, m: i32) -> u32 {
+    match arr[(n as usize, m as usize)] {
+        None => get_recur(arr, n, m),
+        Some(x) => x,
+    }
+}
+fn get_recur(arr: &mut ArrT, n: i32, m: i32) -> u32 {
+    if n < m || n <= 0 || m <= 0 {
+        return 0;
+    }
+    if let Some(ret) = arr[(n as usize, m as usize)] {
+        return ret;
+    }
+    let a = get_recur(arr, n - 1, m - 1);
+    let b = get_recur(arr, n - 2, m - 1);
+    let c = get_recur(arr, n - 3, m - 1);
+
+    let ret = (a + b + c) % MODULO;
+    arr[(n as usize, m as usize)] = Some(ret);
+    ret
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    fn solve<R: BufRead, W: Write>(input: R, output: W) -> solver::Result {
+        let mut dp = Table2D::new(MAX_N + 1, MAX_N + 1, None);
+        dp[(1, 1)] = Some(1);
+        dp[(2, 1)] = Some(1);
+        dp[(3, 1)] = Some(1);
+        let mut sc = Scanner::new(input);
+        let mut out = Output::new(output);
+        let t: usize = sc.next()?;
+
+        for _ in 0..t {
+            let (n, m) = (sc.next()?, sc.next()?);
+            let submit = get(&mut dp, n, m);
+            outln!(out; submit)?;
+        }
+
+        out.flush()?;
+        Ok(())
+    }
+}
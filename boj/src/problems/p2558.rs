@@ -0,0 +1,24 @@
+//! <https://boj.kr/2558>
+
+use std::io::{BufRead, Write};
+
+use crate::{
+    outln,
+    output::Output,
+    scanner::Scanner,
+    solver::{self, Solver},
+};
+
+pub struct Solution;
+
+impl Solver for Solution {
+    fn solve<R: BufRead, W: Write>(input: R, output: W) -> solver::Result {
+        let mut sc = Scanner::new(input);
+        let mut out = Output::new(output);
+        let sum: i32 = sc.next_vec::<i32>(2)?.into_iter().sum();
+        outln!(out; sum)?;
+
+        out.flush()?;
+        Ok(())
+    }
+}
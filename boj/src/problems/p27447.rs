@@ -0,0 +1,395 @@
+//! <https://boj.kr/27447>
+//!
+//! 하루에 한 가지만 한다: 그릇을 빚거나, 그릇에 커피를 끓이거나, 그날 온 손님에게 커피를 내준다.
+//! `t`일에 온 손님에게는 `t - m`일 이후에 끓인 커피만 내줄 수 있다.
+//!
+//! [`Schedule::plan`]이 날마다 할 일을 정하고, [`check`]가 아무 계획이나 규칙에 맞는지 검사한다.
+//! `--explain`을 주면 답 앞에 날마다 한 일을 함께 쓴다.
+
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::io::{self, BufRead, Write};
+
+use crate::{
+    bitset::BitSet,
+    outln,
+    output::Output,
+    scanner::Scanner,
+    solver::{self, Solver},
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Action {
+    MakeBowl,
+    Brew,
+    Serve,
+}
+
+/// `customer`번째(0부터) 손님이 `day`일에 왔는데 내줄 커피가 없었다.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Unserved {
+    pub day: usize,
+    pub customer: usize,
+}
+
+impl Display for Unserved {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "customer {} arriving on day {} cannot be served",
+            self.customer + 1,
+            self.day
+        )
+    }
+}
+
+/// 0일부터 날마다 할 일. 실패하면 못 받은 손님이 온 날 앞에서 끝난다.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Schedule {
+    actions: Vec<Action>,
+    unserved: Option<Unserved>,
+}
+
+impl Schedule {
+    /// `customers`는 손님이 오는 날을 오름차순으로 담는다.
+    ///
+    /// 손님이 오면 커피를 내주고, 아직 커피가 없는 첫 손님까지 `m`일 이내이고 그릇이 있으면
+    /// 커피를 끓이고, 아니면 그릇을 빚는다.
+    pub fn plan(m: usize, customers: &[usize]) -> Self {
+        let arrivals: BitSet = customers.iter().copied().collect();
+        let mut actions = Vec::with_capacity(arrivals.len());
+        let (mut bowls, mut coffees) = (0, 0);
+        // 커피를 끓인 손님 수, 커피를 내준 손님 수
+        let (mut brewed, mut served) = (0, 0);
+
+        for day in 0..arrivals.len() {
+            let action = if arrivals.get(day) {
+                if coffees == 0 {
+                    return Self {
+                        actions,
+                        unserved: Some(Unserved {
+                            day,
+                            customer: served,
+                        }),
+                    };
+                }
+                coffees -= 1;
+                served += 1;
+                Action::Serve
+            } else if bowls > 0 && customers.get(brewed).is_some_and(|&t| t <= day + m) {
+                bowls -= 1;
+                coffees += 1;
+                brewed += 1;
+                Action::Brew
+            } else {
+                bowls += 1;
+                Action::MakeBowl
+            };
+            actions.push(action);
+        }
+
+        Self {
+            actions,
+            unserved: None,
+        }
+    }
+
+    pub fn actions(&self) -> &[Action] {
+        &self.actions
+    }
+
+    pub fn unserved(&self) -> Option<Unserved> {
+        self.unserved
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.unserved.is_none()
+    }
+
+    /// 날마다 한 일을 한 줄씩 쓰고, 실패했으면 못 받은 손님을 쓴다. 손님 번호는 1부터 센다.
+    pub fn explain<O: Write>(&self, out: &mut Output<O>) -> io::Result<()> {
+        let (mut brewed, mut served) = (0, 0);
+        for (day, action) in self.actions.iter().enumerate() {
+            match action {
+                Action::MakeBowl => outln!(out, "day {day}: make a bowl")?,
+                Action::Brew => {
+                    brewed += 1;
+                    outln!(out, "day {day}: brew coffee for customer {brewed}")?;
+                }
+                Action::Serve => {
+                    served += 1;
+                    outln!(out, "day {day}: serve customer {served}")?;
+                }
+            }
+        }
+        if let Some(unserved) = self.unserved {
+            outln!(out, "day {}: {unserved}", unserved.day)?;
+        }
+        Ok(())
+    }
+}
+
+/// [`check`]가 찾은, 계획이 처음으로 규칙을 어긴 곳. 손님 번호는 0부터 센다.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Violation {
+    /// 손님이 온 날에 커피를 내주지 않았다.
+    Busy { day: usize, customer: usize },
+    /// 손님에게 내줄, 끓인 지 `m`일이 지나지 않은 커피가 없다.
+    NoCoffee { day: usize, customer: usize },
+    /// 그릇 없이 커피를 끓였다.
+    NoBowl { day: usize },
+    /// 손님이 없는 날에 커피를 내주었다.
+    NoCustomer { day: usize },
+    /// 계획이 이 손님이 오기 전에 끝났다.
+    Unplanned { day: usize, customer: usize },
+}
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Violation::Busy { day, customer } => {
+                write!(f, "day {day}: customer {} is not served", customer + 1)
+            }
+            Violation::NoCoffee { day, customer } => {
+                write!(
+                    f,
+                    "day {day}: no fresh coffee for customer {}",
+                    customer + 1
+                )
+            }
+            Violation::NoBowl { day } => write!(f, "day {day}: brewing without a bowl"),
+            Violation::NoCustomer { day } => write!(f, "day {day}: serving nobody"),
+            Violation::Unplanned { day, customer } => {
+                write!(f, "day {day}: plan ends before customer {}", customer + 1)
+            }
+        }
+    }
+}
+
+impl Error for Violation {}
+
+/// `actions[d]`가 `d`일에 한 일인 계획이 규칙에 맞는지 검사한다. `customers`는 오름차순.
+///
+/// 커피는 끓인 순서대로 내주고, 너무 오래된 커피는 버린다. 어느 계획이든 이렇게 내주는 것이
+/// 가장 유리하므로 이 검사를 통과하지 못하면 어떻게 내줘도 규칙을 어긴다.
+pub fn check(m: usize, customers: &[usize], actions: &[Action]) -> Result<(), Violation> {
+    let mut bowls = 0;
+    // 아직 내주지 않은 커피를 끓인 날
+    let mut coffees = VecDeque::new();
+    let mut next = 0;
+
+    for (day, &action) in actions.iter().enumerate() {
+        let arriving = customers.get(next).filter(|&&t| t == day).map(|_| next);
+        match (action, arriving) {
+            (Action::Serve, Some(customer)) => {
+                while coffees.front().is_some_and(|&brewed| day - brewed > m) {
+                    coffees.pop_front();
+                }
+                coffees
+                    .pop_front()
+                    .ok_or(Violation::NoCoffee { day, customer })?;
+                next += 1;
+            }
+            (Action::Serve, None) => return Err(Violation::NoCustomer { day }),
+            (_, Some(customer)) => return Err(Violation::Busy { day, customer }),
+            (Action::MakeBowl, None) => bowls += 1,
+            (Action::Brew, None) => {
+                if bowls == 0 {
+                    return Err(Violation::NoBowl { day });
+                }
+                bowls -= 1;
+                coffees.push_back(day);
+            }
+        }
+    }
+
+    match customers.get(next) {
+        Some(&day) => Err(Violation::Unplanned {
+            day,
+            customer: next,
+        }),
+        None => Ok(()),
+    }
+}
+
+fn read_input<R: BufRead>(sc: &mut Scanner<R>) -> solver::Result<(usize, Vec<usize>)> {
+    let (n, m): (usize, usize) = (sc.next()?, sc.next()?);
+    Ok((m, sc.next_vec(n)?))
+}
+
+fn verdict(schedule: &Schedule) -> &'static str {
+    if schedule.is_success() {
+        "success"
+    } else {
+        "fail"
+    }
+}
+
+/// 답 앞에 [`Schedule::explain`]을 쓴다.
+pub fn explain<R: BufRead, W: Write>(input: R, output: W) -> solver::Result {
+    let mut sc = Scanner::new(input);
+    let mut out = Output::new(output);
+    let (m, customers) = read_input(&mut sc)?;
+
+    let schedule = Schedule::plan(m, &customers);
+    schedule.explain(&mut out)?;
+    outln!(out; verdict(&schedule))?;
+
+    out.flush()?;
+    Ok(())
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    fn solve<R: BufRead, W: Write>(input: R, output: W) -> solver::Result {
+        let mut sc = Scanner::new(input);
+        let mut out = Output::new(output);
+        let (m, customers) = read_input(&mut sc)?;
+
+        outln!(out; verdict(&Schedule::plan(m, &customers)))?;
+
+        out.flush()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    use super::*;
+
+    fn run(input: &str, f: fn(&[u8], &mut Vec<u8>) -> solver::Result) -> String {
+        let mut output = vec![];
+        f(input.as_bytes(), &mut output).unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    /// 길이가 `len`인 모든 계획
+    fn all_plans(len: usize) -> impl Iterator<Item = Vec<Action>> {
+        const ACTIONS: [Action; 3] = [Action::MakeBowl, Action::Brew, Action::Serve];
+        (0..3usize.pow(len as u32)).map(move |mut code| {
+            (0..len)
+                .map(|_| {
+                    let action = ACTIONS[code % 3];
+                    code /= 3;
+                    action
+                })
+                .collect()
+        })
+    }
+
+    #[test]
+    fn success() {
+        use Action::*;
+
+        let schedule = Schedule::plan(3, &[5, 6]);
+        assert_eq!(
+            [MakeBowl, MakeBowl, Brew, Brew, MakeBowl, Serve, Serve][..],
+            *schedule.actions()
+        );
+        assert!(schedule.is_success());
+        assert_eq!(Ok(()), check(3, &[5, 6], schedule.actions()));
+        assert_eq!("success\n", run("2 3\n5 6\n", |i, o| Solution::solve(i, o)));
+    }
+
+    #[test]
+    fn fail() {
+        let schedule = Schedule::plan(3, &[3, 4, 5]);
+        let unserved = Unserved {
+            day: 4,
+            customer: 1,
+        };
+        assert_eq!(Some(unserved), schedule.unserved());
+        assert_eq!(4, schedule.actions().len());
+        assert_eq!("fail\n", run("3 3\n3 4 5\n", |i, o| Solution::solve(i, o)));
+        assert_eq!(
+            "customer 2 arriving on day 4 cannot be served",
+            unserved.to_string()
+        );
+    }
+
+    #[test]
+    fn explain_output() {
+        let expected = "\
+day 0: make a bowl
+day 1: brew coffee for customer 1
+day 2: make a bowl
+day 3: serve customer 1
+day 4: customer 2 arriving on day 4 cannot be served
+fail
+";
+        assert_eq!(expected, run("3 3\n3 4 5\n", |i, o| explain(i, o)));
+        assert!(
+            run("2 3\n5 6\n", |i, o| explain(i, o)).ends_with("day 6: serve customer 2\nsuccess\n")
+        );
+    }
+
+    #[test]
+    fn check_violations() {
+        use Action::*;
+
+        assert_eq!(
+            Err(Violation::NoBowl { day: 0 }),
+            check(1, &[2], &[Brew, MakeBowl, Serve])
+        );
+        assert_eq!(
+            Err(Violation::Busy {
+                day: 2,
+                customer: 0
+            }),
+            check(1, &[2], &[MakeBowl, Brew, MakeBowl])
+        );
+        assert_eq!(
+            Err(Violation::NoCustomer { day: 2 }),
+            check(1, &[3], &[MakeBowl, Brew, Serve])
+        );
+        assert_eq!(
+            Err(Violation::NoCoffee {
+                day: 3,
+                customer: 0
+            }),
+            check(1, &[3], &[MakeBowl, Brew, MakeBowl, Serve])
+        );
+        assert_eq!(
+            Err(Violation::Unplanned {
+                day: 3,
+                customer: 0
+            }),
+            check(1, &[3], &[MakeBowl, Brew])
+        );
+        assert_eq!(Ok(()), check(1, &[3], &[MakeBowl, MakeBowl, Brew, Serve]));
+    }
+
+    /// 가능한 모든 계획 중 규칙에 맞는 것이 있을 때만 `plan`이 성공하고, 그 계획도 규칙에 맞는다.
+    #[test]
+    fn same_as_exhaustive_search() {
+        let mut rng = StdRng::seed_from_u64(49);
+        for _ in 0..300 {
+            let m = rng.gen_range(1..=3);
+            let mut customers: Vec<usize> = (1..=8).filter(|_| rng.gen_bool(0.4)).collect();
+            if customers.is_empty() {
+                customers.push(rng.gen_range(1..=8));
+            }
+            let len = customers.last().unwrap() + 1;
+
+            let schedule = Schedule::plan(m, &customers);
+            let possible = all_plans(len).any(|plan| check(m, &customers, &plan).is_ok());
+            assert_eq!(possible, schedule.is_success(), "m = {m}, {customers:?}");
+
+            match schedule.unserved() {
+                None => assert_eq!(Ok(()), check(m, &customers, schedule.actions())),
+                Some(Unserved { day, customer }) => {
+                    let mut actions = schedule.actions().to_vec();
+                    actions.push(Action::Serve);
+                    assert_eq!(
+                        Err(Violation::NoCoffee { day, customer }),
+                        check(m, &customers, &actions)
+                    );
+                }
+            }
+        }
+    }
+}
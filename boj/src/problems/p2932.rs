@@ -0,0 +1,499 @@
+//! <https://boj.kr/2932>
+
+use std::collections::{HashMap, HashSet};
+use std::fmt::{self, Debug, Display};
+use std::io::{BufRead, Write};
+
+use rand::{rngs::StdRng, Rng};
+
+use crate::{
+    outln,
+    output::Output,
+    scanner::Scanner,
+    solver::{self, Solver},
+    stress::Case,
+    torus::{Query, Rotate},
+};
+
+type Id = i32;
+type Index = i32;
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct Block {
+    id: Id,
+    row: Index,
+    col: Index,
+}
+
+/// 질문에 나온 블록들만 들고 있는 표.
+///
+/// 블록은 `blocks`에 한 번 넣으면 자리(slot)가 바뀌지 않는다. 번호로 자리를 찾는 `slots`와
+/// 행/열마다 그 위에 있는 자리들을 모은 `rows`/`cols`를 함께 고쳐서, 한 줄을 돌릴 때는
+/// 그 줄에 있는 블록만 건드린다.
+pub struct Database {
+    blocks: Vec<Block>,
+    slots: HashMap<Id, usize>,
+    rows: HashMap<Index, HashSet<usize>>,
+    cols: HashMap<Index, HashSet<usize>>,
+    n: i32,
+}
+
+pub struct DatabaseBuilder {
+    entries: Vec<Block>,
+    n: i32,
+}
+
+enum RowOrCol {
+    Row(Index),
+    Col(Index),
+}
+
+/// `slot`을 `from` 줄에서 빼서 `to` 줄로 옮긴다.
+fn move_slot(lines: &mut HashMap<Index, HashSet<usize>>, slot: usize, from: Index, to: Index) {
+    if let Some(line) = lines.get_mut(&from) {
+        line.remove(&slot);
+        if line.is_empty() {
+            lines.remove(&from);
+        }
+    }
+    lines.entry(to).or_default().insert(slot);
+}
+
+impl Database {
+    /// rotate given line amount times,
+    /// if line is `Row`, rotate this row right,
+    /// if line is `Col`, rotate this col down.
+    fn do_rotate(&mut self, line: RowOrCol, amount: i32) {
+        if amount == 0 {
+            return;
+        }
+        match line {
+            RowOrCol::Row(row) => {
+                // rotate this row to the right
+                for &slot in self.rows.get(&row).into_iter().flatten() {
+                    let block = &mut self.blocks[slot];
+                    let col = (block.col + amount) % self.n;
+                    move_slot(&mut self.cols, slot, block.col, col);
+                    block.col = col;
+                }
+            }
+            RowOrCol::Col(col) => {
+                for &slot in self.cols.get(&col).into_iter().flatten() {
+                    let block = &mut self.blocks[slot];
+                    let row = (block.row + amount) % self.n;
+                    move_slot(&mut self.rows, slot, block.row, row);
+                    block.row = row;
+                }
+            }
+        }
+    }
+}
+
+impl Debug for Block {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({:?}, {:?}, {:?})", self.id, self.row, self.col)
+    }
+}
+
+impl Debug for Database {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Database")
+            .field("entries", &self.blocks)
+            .finish()
+    }
+}
+
+impl Rotate for Database {
+    fn rows(&self) -> usize {
+        self.n as usize
+    }
+
+    fn cols(&self) -> usize {
+        self.n as usize
+    }
+
+    fn rotate_row(&mut self, row: usize, by: usize) {
+        self.do_rotate(RowOrCol::Row(row as Index), (by % self.cols()) as i32);
+    }
+
+    fn rotate_col(&mut self, col: usize, by: usize) {
+        self.do_rotate(RowOrCol::Col(col as Index), (by % self.rows()) as i32);
+    }
+}
+
+impl Query for Database {
+    type Value = Id;
+
+    fn find(&self, id: &Id) -> Option<(usize, usize)> {
+        let block = self.blocks[*self.slots.get(id)?];
+        Some((block.row as usize, block.col as usize))
+    }
+}
+
+impl DatabaseBuilder {
+    pub fn new(n: i32) -> Self {
+        DatabaseBuilder { entries: vec![], n }
+    }
+    pub fn add_entry(&mut self, id: Id) {
+        let block = Block {
+            id,
+            row: id / self.n,
+            col: id % self.n,
+        };
+        self.entries.push(block);
+    }
+    /// 같은 번호를 여러 번 넣었으면 하나만 남긴다.
+    pub fn build(self) -> Database {
+        let mut db = Database {
+            blocks: vec![],
+            slots: HashMap::new(),
+            rows: HashMap::new(),
+            cols: HashMap::new(),
+            n: self.n,
+        };
+        for block in self.entries {
+            if db.slots.contains_key(&block.id) {
+                continue;
+            }
+            let slot = db.blocks.len();
+            db.blocks.push(block);
+            db.slots.insert(block.id, slot);
+            db.rows.entry(block.row).or_default().insert(slot);
+            db.cols.entry(block.col).or_default().insert(slot);
+        }
+        db
+    }
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    fn solve<R: BufRead, W: Write>(input: R, output: W) -> solver::Result {
+        let mut sc = Scanner::new(input);
+        let mut out = Output::new(output);
+        let (n, k): (i32, i32) = (sc.next()?, sc.next()?);
+
+        let mut builder = DatabaseBuilder::new(n);
+        let mut pending = vec![];
+
+        for _ in 0..k {
+            let (id, row, col): (Id, Index, Index) = (sc.next()?, sc.next()?, sc.next()?);
+            builder.add_entry(id - 1);
+            pending.push((id as Id, row - 1 as Index, col - 1 as Index)); // because the problem starts index with 1
+        }
+
+        let mut db = builder.build();
+
+        for (id, row, col) in pending {
+            let pos = db.get_pos(id - 1).expect("id not found");
+            let submit = db.rotate(pos, (row as usize, col as usize));
+            outln!(out; submit)?;
+        }
+
+        out.flush()?;
+        Ok(())
+    }
+}
+
+/// stress test용 무작위 입력. 번호와 좌표는 문제처럼 1부터 센다.
+#[derive(Clone, Debug)]
+pub struct Input {
+    n: usize,
+    /// `(번호, 행, 열)`
+    queries: Vec<(usize, usize, usize)>,
+}
+
+impl Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} {}", self.n, self.queries.len())?;
+        for (id, row, col) in &self.queries {
+            writeln!(f, "{id} {row} {col}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Case for Input {
+    fn generate(rng: &mut StdRng) -> Self {
+        let n = rng.gen_range(1..=6);
+        let queries = (0..rng.gen_range(1..=10))
+            .map(|_| {
+                (
+                    rng.gen_range(1..=n * n),
+                    rng.gen_range(1..=n),
+                    rng.gen_range(1..=n),
+                )
+            })
+            .collect();
+        Self { n, queries }
+    }
+
+    fn shrink(&self) -> Vec<Self> {
+        let mut candidates = vec![];
+        for i in 0..self.queries.len() {
+            if self.queries.len() > 1 {
+                let mut smaller = self.clone();
+                smaller.queries.remove(i);
+                candidates.push(smaller);
+            }
+        }
+        if self.n > 1 {
+            let n = self.n - 1;
+            let queries: Vec<_> = self
+                .queries
+                .iter()
+                .copied()
+                .filter(|&(id, row, col)| id <= n * n && row <= n && col <= n)
+                .collect();
+            if !queries.is_empty() {
+                candidates.push(Self { n, queries });
+            }
+        }
+        // 목표 위치를 (1, 1) 쪽으로 당긴다.
+        for (i, &(id, row, col)) in self.queries.iter().enumerate() {
+            for target in [(row - 1, col), (row, col - 1)] {
+                if target.0 >= 1 && target.1 >= 1 {
+                    let mut smaller = self.clone();
+                    smaller.queries[i] = (id, target.0, target.1);
+                    candidates.push(smaller);
+                }
+            }
+        }
+        candidates
+    }
+}
+
+/// `n`×`n` 표 전체를 들고 문제에 적힌 대로 행과 열을 한 칸씩 돌린다.
+pub struct BruteForce;
+
+impl Solver for BruteForce {
+    fn solve<R: BufRead, W: Write>(input: R, output: W) -> solver::Result {
+        let mut sc = Scanner::new(input);
+        let mut out = Output::new(output);
+        let (n, k): (usize, usize) = (sc.next()?, sc.next()?);
+        let mut table: Vec<Vec<usize>> = (0..n)
+            .map(|i| (1..=n).map(|j| i * n + j).collect())
+            .collect();
+
+        for _ in 0..k {
+            let (id, row, col): (usize, usize, usize) = (sc.next()?, sc.next()?, sc.next()?);
+            let mut moves = 0;
+            // 행을 오른쪽으로 한 칸씩
+            loop {
+                let r = table.iter().position(|line| line.contains(&id)).unwrap();
+                if table[r][col - 1] == id {
+                    break;
+                }
+                table[r].rotate_right(1);
+                moves += 1;
+            }
+            // 열을 아래로 한 칸씩
+            while table[row - 1][col - 1] != id {
+                let last = table[n - 1][col - 1];
+                for r in (1..n).rev() {
+                    table[r][col - 1] = table[r - 1][col - 1];
+                }
+                table[0][col - 1] = last;
+                moves += 1;
+            }
+            outln!(out; moves)?;
+        }
+
+        out.flush()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Instant;
+
+    use rand::SeedableRng;
+
+    use super::*;
+    use crate::stress;
+
+    /// 색인 없이 모든 블록을 훑는 예전 구현. [`Database`]와 비교하는 데 쓴다.
+    struct ScanDatabase {
+        entries: Vec<Block>,
+        n: i32,
+    }
+
+    impl ScanDatabase {
+        fn new(n: i32, ids: &[Id]) -> Self {
+            let entries = ids
+                .iter()
+                .map(|&id| Block {
+                    id,
+                    row: id / n,
+                    col: id % n,
+                })
+                .collect();
+            Self { entries, n }
+        }
+
+        fn do_rotate(&mut self, line: RowOrCol, amount: i32) {
+            match line {
+                RowOrCol::Row(row) => self
+                    .entries
+                    .iter_mut()
+                    .filter(|e| e.row == row)
+                    .for_each(|e| e.col = (e.col + amount) % self.n),
+                RowOrCol::Col(col) => self
+                    .entries
+                    .iter_mut()
+                    .filter(|e| e.col == col)
+                    .for_each(|e| e.row = (e.row + amount) % self.n),
+            }
+        }
+    }
+
+    impl Rotate for ScanDatabase {
+        fn rows(&self) -> usize {
+            self.n as usize
+        }
+
+        fn cols(&self) -> usize {
+            self.n as usize
+        }
+
+        fn rotate_row(&mut self, row: usize, by: usize) {
+            self.do_rotate(RowOrCol::Row(row as Index), (by % self.cols()) as i32);
+        }
+
+        fn rotate_col(&mut self, col: usize, by: usize) {
+            self.do_rotate(RowOrCol::Col(col as Index), (by % self.rows()) as i32);
+        }
+    }
+
+    impl Query for ScanDatabase {
+        type Value = Id;
+
+        fn find(&self, id: &Id) -> Option<(usize, usize)> {
+            self.entries
+                .iter()
+                .find(|&block| block.id == *id)
+                .map(|block| (block.row as usize, block.col as usize))
+        }
+    }
+
+    /// `(번호, 목표 행, 목표 열)`, 모두 0부터
+    fn random_queries(seed: u64, n: i32, k: usize) -> Vec<(Id, Index, Index)> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        (0..k)
+            .map(|_| {
+                (
+                    rng.gen_range(0..n * n),
+                    rng.gen_range(0..n),
+                    rng.gen_range(0..n),
+                )
+            })
+            .collect()
+    }
+
+    fn run(
+        db: &mut (impl Rotate + Query<Value = Id>),
+        queries: &[(Id, Index, Index)],
+    ) -> Vec<usize> {
+        queries
+            .iter()
+            .map(|&(id, row, col)| db.rotate(db.get_pos(id).unwrap(), (row as usize, col as usize)))
+            .collect()
+    }
+
+    fn both(n: i32, queries: &[(Id, Index, Index)]) -> (Database, ScanDatabase) {
+        let ids: Vec<_> = queries.iter().map(|&(id, _, _)| id).collect();
+        let mut builder = DatabaseBuilder::new(n);
+        ids.iter().for_each(|&id| builder.add_entry(id));
+        (builder.build(), ScanDatabase::new(n, &ids))
+    }
+    #[test]
+    fn index() {
+        const N: i32 = 4;
+        let mut builder = DatabaseBuilder::new(N);
+        for i in 0..N * N {
+            builder.add_entry(i);
+        }
+        let db = builder.build();
+        for i in 0..N * N {
+            assert_eq!(((i / N) as usize, (i % N) as usize), db.find(&i).unwrap());
+        }
+    }
+
+    #[test]
+    fn stress() {
+        stress::check::<Input, Solution, BruteForce>();
+    }
+
+    #[test]
+    fn same_as_scan() {
+        for seed in 0..200 {
+            let n = 1 + seed as i32 % 9;
+            let queries = random_queries(seed, n, 40);
+            let (mut db, mut scan) = both(n, &queries);
+            assert_eq!(
+                run(&mut scan, &queries),
+                run(&mut db, &queries),
+                "seed {seed}"
+            );
+            for &(id, _, _) in &queries {
+                assert_eq!(scan.find(&id), db.find(&id));
+            }
+        }
+    }
+
+    /// 표 전체를 들고 한 칸씩 돌리는 `Vec<Vec<Id>>`와 비교한다.
+    #[test]
+    fn same_as_full_table() {
+        for seed in 0..100 {
+            let n = 1 + seed as i32 % 6;
+            let queries = random_queries(seed, n, 20);
+            let (mut db, _) = both(n, &queries);
+            let mut table: Vec<Vec<Id>> = (0..n)
+                .map(|i| (0..n).map(|j| i * n + j).collect())
+                .collect();
+            assert_eq!(
+                run(&mut table, &queries),
+                run(&mut db, &queries),
+                "seed {seed}"
+            );
+            for &(id, _, _) in &queries {
+                assert_eq!(table.find(&id), db.find(&id));
+            }
+        }
+    }
+
+    #[test]
+    fn duplicate_ids_move_together() {
+        let mut builder = DatabaseBuilder::new(3);
+        builder.add_entry(4);
+        builder.add_entry(4);
+        let mut db = builder.build();
+        assert_eq!(4, db.rotate(db.get_pos(4).unwrap(), (0, 0)));
+        assert_eq!(Some((0, 0)), db.find(&4));
+    }
+
+    /// 문제의 최대 크기보다 큰 K에서 두 구현을 잰다.
+    /// `cargo test --release -p boj p2932::test::bench -- --ignored --nocapture`
+    #[test]
+    #[ignore]
+    fn bench() {
+        const N: i32 = 7000;
+        for k in [1_000, 10_000, 50_000] {
+            let queries = random_queries(k as u64, N, k);
+            let (mut db, mut scan) = both(N, &queries);
+
+            let time = |f: &mut dyn FnMut() -> Vec<usize>| {
+                let start = Instant::now();
+                let answers = f();
+                (answers, start.elapsed())
+            };
+            let (indexed, indexed_time) = time(&mut || run(&mut db, &queries));
+            let (scanned, scan_time) = time(&mut || run(&mut scan, &queries));
+            assert_eq!(scanned, indexed);
+            let speedup = scan_time.as_secs_f64() / indexed_time.as_secs_f64();
+            println!(
+                "K = {k:>6}: indexed {indexed_time:>10.2?}, scan {scan_time:>10.2?} ({speedup:.1}x)"
+            );
+        }
+    }
+}
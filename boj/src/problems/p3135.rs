@@ -0,0 +1,54 @@
+//! <https://boj.kr/3135>
+
+use std::{
+    cmp::{
+        self,
+        Ordering::{Equal, Greater, Less},
+    },
+    io::{BufRead, Write},
+};
+
+use crate::{
+    outln,
+    output::Output,
+    scanner::{self, Scanner},
+    solver::{self, Solver},
+};
+
+pub struct Solution;
+
+impl Solver for Solution {
+    fn solve<R: BufRead, W: Write>(input: R, output: W) -> solver::Result {
+        let mut sc = Scanner::new(input);
+        let mut out = Output::new(output);
+        let (a, b): (i32, i32) = (sc.next()?, sc.next()?);
+        let n: i32 = sc.next()?;
+
+        let submit = solution(&mut sc, a, b, n)?;
+
+        outln!(out; submit)?;
+
+        out.flush()?;
+        Ok(())
+    }
+}
+
+fn dist(lhs: i32, rhs: i32) -> i32 {
+    if lhs < rhs {
+        rhs - lhs
+    } else {
+        lhs - rhs
+    }
+}
+
+fn solution<R: BufRead>(sc: &mut Scanner<R>, a: i32, b: i32, n: i32) -> scanner::Result<i32> {
+    let mut min_dist = i32::MAX;
+    for _ in 0..n {
+        let each_button: i32 = sc.next()?;
+        min_dist = cmp::min(min_dist, dist(each_button, b));
+    }
+    Ok(match dist(a, b).cmp(&min_dist) {
+        Less | Equal => dist(a, b),
+        Greater => min_dist + 1,
+    })
+}
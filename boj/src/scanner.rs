@@ -0,0 +1,282 @@
+//! 공백으로 구분된 입력을 토큰 단위로 읽는 [`Scanner`].
+//!
+//! 풀이마다 `stdin().lines()...split(' ').map(parse)`를 반복하던 것을 대신한다.
+//! 줄바꿈과 공백(여러 칸, 탭, `\r` 포함)을 구분하지 않고 토큰을 이어서 읽으므로 입력이
+//! 몇 줄에 걸쳐 있는지 신경 쓸 필요가 없다.
+//!
+//! ```
+//! use boj::scanner::Scanner;
+//!
+//! let mut sc = Scanner::new("2 3\n1 2 3\n4 5 6\n".as_bytes());
+//! let (n, m): (usize, usize) = (sc.next().unwrap(), sc.next().unwrap());
+//! let grid: Vec<Vec<i32>> = sc.next_grid(n, m).unwrap();
+//! assert_eq!(vec![vec![1, 2, 3], vec![4, 5, 6]], grid);
+//! assert_eq!(None, sc.try_next::<i32>().unwrap());
+//! ```
+
+use std::{
+    any::type_name,
+    error::Error,
+    fmt::{self, Display},
+    io::{self, BufRead, StdinLock},
+    str::FromStr,
+};
+
+/// [`Scanner`]가 돌려주는 에러
+#[derive(Debug)]
+pub enum ScanError {
+    /// 입력을 읽다가 실패했다.
+    Io(io::Error),
+    /// 토큰이 더 필요한데 입력이 끝났다.
+    Eof,
+    /// 토큰을 원하는 타입으로 바꾸지 못했다.
+    Parse {
+        token: String,
+        type_name: &'static str,
+        reason: String,
+    },
+}
+
+pub type Result<T> = std::result::Result<T, ScanError>;
+
+/// 아무 [`BufRead`] 위에서 한 줄씩 버퍼에 읽어 두고 토큰을 잘라 준다.
+#[derive(Debug)]
+pub struct Scanner<R> {
+    reader: R,
+    /// 현재 줄. `pos` 앞쪽은 이미 소비했다.
+    line: String,
+    pos: usize,
+}
+
+impl Display for ScanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScanError::Io(err) => write!(f, "failed to read input: {err}"),
+            ScanError::Eof => write!(f, "unexpected end of input"),
+            ScanError::Parse {
+                token,
+                type_name,
+                reason,
+            } => write!(f, "cannot parse {token:?} as `{type_name}`: {reason}"),
+        }
+    }
+}
+
+impl Error for ScanError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ScanError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for ScanError {
+    fn from(err: io::Error) -> Self {
+        ScanError::Io(err)
+    }
+}
+
+impl Scanner<StdinLock<'static>> {
+    /// 표준 입력을 잠근 채로 읽는다.
+    pub fn stdin() -> Self {
+        Self::new(io::stdin().lock())
+    }
+}
+
+impl<R: BufRead> Scanner<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            line: String::new(),
+            pos: 0,
+        }
+    }
+
+    /// 다음 줄을 `line`에 채운다. 입력이 끝났으면 `false`.
+    fn fill_line(&mut self) -> Result<bool> {
+        self.line.clear();
+        self.pos = 0;
+        Ok(self.reader.read_line(&mut self.line)? > 0)
+    }
+
+    /// 다음 토큰의 위치. 현재 줄에 남은 토큰이 없으면 다음 줄을 읽는다.
+    fn next_token(&mut self) -> Result<Option<&str>> {
+        let start = loop {
+            let rest = &self.line[self.pos..];
+            if let Some(offset) = rest.find(|c: char| !c.is_ascii_whitespace()) {
+                break self.pos + offset;
+            }
+            if !self.fill_line()? {
+                return Ok(None);
+            }
+        };
+        let end = self.line[start..]
+            .find(|c: char| c.is_ascii_whitespace())
+            .map_or(self.line.len(), |len| start + len);
+        self.pos = end;
+        Ok(Some(&self.line[start..end]))
+    }
+
+    /// 다음 토큰을 `T`로 읽는다. 입력이 끝났으면 `Ok(None)`.
+    ///
+    /// 테스트 케이스 개수 없이 EOF까지 읽어야 하는 문제에 쓴다.
+    pub fn try_next<T>(&mut self) -> Result<Option<T>>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let Some(token) = self.next_token()? else {
+            return Ok(None);
+        };
+        token
+            .parse()
+            .map(Some)
+            .map_err(|err: T::Err| ScanError::Parse {
+                token: token.to_owned(),
+                type_name: type_name::<T>(),
+                reason: err.to_string(),
+            })
+    }
+
+    /// 다음 토큰을 `T`로 읽는다. 입력이 끝났으면 [`ScanError::Eof`].
+    #[allow(clippy::should_implement_trait)]
+    pub fn next<T>(&mut self) -> Result<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.try_next()?.ok_or(ScanError::Eof)
+    }
+
+    /// 토큰 `n`개를 읽는다.
+    pub fn next_vec<T>(&mut self, n: usize) -> Result<Vec<T>>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        (0..n).map(|_| self.next()).collect()
+    }
+
+    /// `rows`×`cols`개의 토큰을 행 단위로 읽는다.
+    pub fn next_grid<T>(&mut self, rows: usize, cols: usize) -> Result<Vec<Vec<T>>>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        (0..rows).map(|_| self.next_vec(cols)).collect()
+    }
+
+    /// 현재 줄에서 아직 읽지 않은 부분을 줄바꿈 없이 돌려준다. 현재 줄을 다 읽었다면
+    /// 다음 줄 전체를 돌려준다.
+    ///
+    /// `1,2`처럼 공백이 아닌 구분자를 쓰는 입력이나 공백이 의미 있는 입력에 쓴다.
+    pub fn next_line(&mut self) -> Result<String> {
+        if self.line[self.pos..].trim().is_empty() && !self.fill_line()? {
+            return Err(ScanError::Eof);
+        }
+        let line = self.line[self.pos..]
+            .trim_end_matches(['\n', '\r'])
+            .to_owned();
+        self.pos = self.line.len();
+        Ok(line)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scanner(input: &str) -> Scanner<&[u8]> {
+        Scanner::new(input.as_bytes())
+    }
+
+    #[test]
+    fn tokens_across_lines() {
+        let mut sc = scanner("1  2\t3\r\n\n  -4\n5");
+        let nums: Vec<i32> = sc.next_vec(5).unwrap();
+        assert_eq!(vec![1, 2, 3, -4, 5], nums);
+        assert!(matches!(sc.next::<i32>(), Err(ScanError::Eof)));
+    }
+
+    #[test]
+    fn try_next_until_eof() {
+        let mut sc = scanner("1 2\n3 4\n\n");
+        let mut sum = 0;
+        while let Some(n) = sc.try_next::<i64>().unwrap() {
+            sum += n;
+        }
+        assert_eq!(10, sum);
+        // EOF를 만난 뒤에도 계속 `None`
+        assert_eq!(None, sc.try_next::<i64>().unwrap());
+    }
+
+    #[test]
+    fn mixed_types() {
+        let mut sc = scanner("abc 3.5 x 18446744073709551615");
+        assert_eq!("abc", sc.next::<String>().unwrap());
+        assert_eq!(3.5, sc.next::<f64>().unwrap());
+        assert_eq!('x', sc.next::<char>().unwrap());
+        assert_eq!(u64::MAX, sc.next::<u64>().unwrap());
+    }
+
+    #[test]
+    fn parse_error() {
+        let mut sc = scanner("12 ab");
+        assert_eq!(12u8, sc.next().unwrap());
+        let err = sc.next::<u8>().unwrap_err();
+        let ScanError::Parse {
+            token, type_name, ..
+        } = &err
+        else {
+            panic!("expected parse error, got {err:?}");
+        };
+        assert_eq!("ab", token);
+        assert_eq!("u8", *type_name);
+        assert_eq!(
+            "cannot parse \"ab\" as `u8`: invalid digit found in string",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn grid() {
+        let mut sc = scanner("2 3\n1 0 1\n0 1 0\n");
+        let (n, m) = (sc.next().unwrap(), sc.next().unwrap());
+        let grid: Vec<Vec<u8>> = sc.next_grid(n, m).unwrap();
+        assert_eq!(vec![vec![1, 0, 1], vec![0, 1, 0]], grid);
+        assert!(matches!(sc.next_grid::<u8>(1, 1), Err(ScanError::Eof)));
+    }
+
+    #[test]
+    fn next_line() {
+        let mut sc = scanner("2\n1,2\r\n  3 , 4 \nlast");
+        assert_eq!(2, sc.next::<usize>().unwrap());
+        // `2` 뒤에 남은 건 줄바꿈뿐이니 다음 줄을 통째로 읽는다.
+        assert_eq!("1,2", sc.next_line().unwrap());
+        assert_eq!("  3 , 4 ", sc.next_line().unwrap());
+        assert_eq!("last", sc.next::<String>().unwrap());
+        assert!(matches!(sc.next_line(), Err(ScanError::Eof)));
+    }
+
+    #[test]
+    fn next_line_returns_rest_of_current_line() {
+        let mut sc = scanner("3 hello world\n");
+        assert_eq!(3, sc.next::<i32>().unwrap());
+        assert_eq!(" hello world", sc.next_line().unwrap());
+    }
+
+    #[test]
+    fn io_error() {
+        struct Broken;
+        impl io::Read for Broken {
+            fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+                Err(io::Error::other("broken pipe"))
+            }
+        }
+        let mut sc = Scanner::new(io::BufReader::new(Broken));
+        let err = sc.next::<i32>().unwrap_err();
+        assert!(matches!(err, ScanError::Io(_)));
+        assert!(err.source().is_some());
+    }
+}
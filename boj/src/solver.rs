@@ -0,0 +1,36 @@
+//! 모든 풀이가 따르는 [`Solver`] 트레이트.
+//!
+//! 풀이는 표준 입출력 대신 아무 [`BufRead`]/[`Write`]나 받으므로, 프로세스를 띄우지 않고도
+//! 바이트 배열을 넣고 결과를 받아 테스트하거나 벤치마크할 수 있다.
+//!
+//! ```
+//! use boj::{problems::p10950::Solution, solver::Solver};
+//!
+//! let mut output = vec![];
+//! Solution::solve("2\n1 1\n2 3\n".as_bytes(), &mut output).unwrap();
+//! assert_eq!("2\n5\n", String::from_utf8(output).unwrap());
+//! ```
+
+use std::{
+    error::Error,
+    io::{self, BufRead, Write},
+};
+
+pub type Result<T = ()> = std::result::Result<T, Box<dyn Error>>;
+
+pub trait Solver {
+    /// `input`을 끝까지 읽고 답을 `output`에 쓴다. 돌아오기 전에 `output`을 flush한다.
+    fn solve<R: BufRead, W: Write>(input: R, output: W) -> Result;
+
+    /// 표준 입출력으로 푼다. `src/bin/<problem>.rs`의 `main`이 부른다.
+    fn main() -> Result {
+        Self::solve(io::stdin().lock(), io::stdout().lock())
+    }
+
+    /// 문자열 입력을 받아 출력을 문자열로 돌려준다. 테스트용.
+    fn solve_str(input: &str) -> Result<String> {
+        let mut output = vec![];
+        Self::solve(input.as_bytes(), &mut output)?;
+        Ok(String::from_utf8(output)?)
+    }
+}
@@ -0,0 +1,252 @@
+//! 무작위 입력으로 풀이와 느리지만 확실한 풀이(brute force)를 비교한다.
+//!
+//! 문제마다 입력을 구조체로 만들어 [`Case`]를 구현하면 [`check`]가
+//!
+//! 1. seed마다 입력을 만들어 텍스트로 바꾼 뒤 두 풀이에 넣고,
+//! 2. 출력이 다르거나 한쪽이 에러/panic을 내면,
+//! 3. [`Case::shrink`]가 내놓는 더 작은 입력 중 여전히 틀리는 것으로 계속 줄여서
+//!
+//! 가장 작은 반례를 보여 준다. 같은 seed는 항상 같은 입력을 만든다.
+//!
+//! seed 개수는 `BOJ_STRESS_SEEDS` 환경 변수로 바꿀 수 있다.
+
+use std::{
+    env,
+    fmt::{self, Debug, Display},
+    ops::Range,
+    panic::{self, AssertUnwindSafe},
+};
+
+use rand::{rngs::StdRng, SeedableRng};
+
+use crate::solver::Solver;
+
+/// 기본으로 돌려 볼 seed 개수
+pub const DEFAULT_SEEDS: u64 = 2000;
+
+/// 무작위로 만들 수 있고 더 작게 줄일 수 있는 입력.
+/// `Display`는 문제의 입력 형식 그대로 출력해야 한다.
+pub trait Case: Clone + Display {
+    fn generate(rng: &mut StdRng) -> Self;
+
+    /// 이 입력보다 "작은" 후보들. 앞쪽 후보를 먼저 시도한다.
+    /// 줄이는 과정이 끝나도록 후보는 항상 원래 입력보다 작아야 한다.
+    fn shrink(&self) -> Vec<Self>;
+}
+
+/// 두 풀이가 다르게 답한 입력
+pub struct Failure<C> {
+    pub seed: u64,
+    /// 줄이기 전 입력
+    pub original: C,
+    /// 줄인 뒤 입력
+    pub input: C,
+    pub expected: String,
+    pub actual: String,
+    pub shrink_steps: usize,
+}
+
+impl<C: Display> Display for Failure<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "mismatch at seed {} (shrunk {} times)",
+            self.seed, self.shrink_steps
+        )?;
+        writeln!(f, "--- input\n{}", self.input.to_string().trim_end())?;
+        writeln!(f, "--- expected (reference)\n{}", self.expected.trim_end())?;
+        write!(f, "--- actual\n{}", self.actual.trim_end())
+    }
+}
+
+impl<C: Display> Debug for Failure<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+/// `BOJ_STRESS_SEEDS`가 있으면 그 개수만큼, 없으면 [`DEFAULT_SEEDS`]개
+pub fn seeds() -> Range<u64> {
+    let count = env::var("BOJ_STRESS_SEEDS")
+        .ok()
+        .and_then(|count| count.parse().ok())
+        .unwrap_or(DEFAULT_SEEDS);
+    0..count
+}
+
+/// 풀이의 출력. 에러나 panic도 비교할 수 있도록 문자열로 바꾼다.
+fn outcome<S: Solver>(input: &str) -> String {
+    match panic::catch_unwind(AssertUnwindSafe(|| S::solve_str(input))) {
+        Ok(Ok(output)) => output,
+        Ok(Err(err)) => format!("<error: {err}>"),
+        Err(payload) => {
+            let msg = payload
+                .downcast_ref::<&str>()
+                .map(|msg| msg.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_default();
+            format!("<panic: {msg}>")
+        }
+    }
+}
+
+/// 다르게 답하면 `(기대값, 실제값)`
+fn compare<S: Solver, B: Solver>(input: &str) -> Option<(String, String)> {
+    let expected = outcome::<B>(input);
+    let actual = outcome::<S>(input);
+    (expected != actual).then_some((expected, actual))
+}
+
+/// `seeds`마다 입력을 만들어 `S`와 기준 풀이 `B`를 비교한다.
+/// 처음 틀린 입력을 줄여서 돌려준다.
+pub fn run<C: Case, S: Solver, B: Solver>(seeds: Range<u64>) -> Result<(), Failure<C>> {
+    for seed in seeds {
+        let original = C::generate(&mut StdRng::seed_from_u64(seed));
+        let Some((mut expected, mut actual)) = compare::<S, B>(&original.to_string()) else {
+            continue;
+        };
+
+        // 틀리는 후보가 더 없을 때까지 첫 번째로 틀리는 후보를 따라간다.
+        let mut input = original.clone();
+        let mut shrink_steps = 0;
+        'shrink: loop {
+            for candidate in input.shrink() {
+                if let Some(diff) = compare::<S, B>(&candidate.to_string()) {
+                    (expected, actual) = diff;
+                    input = candidate;
+                    shrink_steps += 1;
+                    continue 'shrink;
+                }
+            }
+            break;
+        }
+
+        return Err(Failure {
+            seed,
+            original,
+            input,
+            expected,
+            actual,
+            shrink_steps,
+        });
+    }
+    Ok(())
+}
+
+/// [`run`]을 [`seeds`]로 돌리고 틀리면 panic한다. 테스트에서 쓴다.
+pub fn check<C: Case, S: Solver, B: Solver>() {
+    if let Err(failure) = run::<C, S, B>(seeds()) {
+        panic!("{failure}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, Write};
+
+    use rand::Rng;
+
+    use super::*;
+    use crate::{outln, output::Output, scanner::Scanner, solver};
+
+    /// 수열의 최댓값을 구하는 문제
+    #[derive(Clone, Debug)]
+    struct Seq(Vec<u32>);
+
+    impl Display for Seq {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            writeln!(f, "{}", self.0.len())?;
+            let nums: Vec<_> = self.0.iter().map(u32::to_string).collect();
+            writeln!(f, "{}", nums.join(" "))
+        }
+    }
+
+    impl Case for Seq {
+        fn generate(rng: &mut StdRng) -> Self {
+            let len = rng.gen_range(1..=20);
+            Seq((0..len).map(|_| rng.gen_range(0..100)).collect())
+        }
+
+        fn shrink(&self) -> Vec<Self> {
+            let mut candidates = vec![];
+            for i in 0..self.0.len() {
+                if self.0.len() > 1 {
+                    let mut smaller = self.0.clone();
+                    smaller.remove(i);
+                    candidates.push(Seq(smaller));
+                }
+                if self.0[i] > 0 {
+                    let mut smaller = self.0.clone();
+                    smaller[i] /= 2;
+                    candidates.push(Seq(smaller));
+                }
+            }
+            candidates
+        }
+    }
+
+    fn read(input: impl BufRead) -> solver::Result<Vec<u32>> {
+        let mut sc = Scanner::new(input);
+        let n = sc.next()?;
+        Ok(sc.next_vec(n)?)
+    }
+
+    struct Max;
+    impl Solver for Max {
+        fn solve<R: BufRead, W: Write>(input: R, output: W) -> solver::Result {
+            let mut out = Output::new(output);
+            outln!(out; read(input)?.into_iter().max().unwrap())?;
+            Ok(out.flush()?)
+        }
+    }
+
+    /// 64 이상인 값은 무시하는 틀린 풀이
+    struct BuggyMax;
+    impl Solver for BuggyMax {
+        fn solve<R: BufRead, W: Write>(input: R, output: W) -> solver::Result {
+            let mut out = Output::new(output);
+            let nums = read(input)?;
+            outln!(out; nums.into_iter().filter(|&n| n < 64).max().unwrap_or(0))?;
+            Ok(out.flush()?)
+        }
+    }
+
+    /// 첫 값을 읽다가 panic하는 풀이
+    struct Panics;
+    impl Solver for Panics {
+        fn solve<R: BufRead, W: Write>(input: R, _: W) -> solver::Result {
+            let nums = read(input)?;
+            panic!("index out of bounds: {}", nums[nums.len()]);
+        }
+    }
+
+    #[test]
+    fn same_solver_passes() {
+        assert!(run::<Seq, Max, Max>(0..200).is_ok());
+    }
+
+    #[test]
+    fn shrinks_to_minimal_counterexample() {
+        let failure = run::<Seq, BuggyMax, Max>(0..200).unwrap_err();
+        // 가장 작은 반례는 64 이상인 값 하나만 남은 수열이다.
+        assert_eq!(1, failure.input.0.len(), "{failure}");
+        assert!((64..100).contains(&failure.input.0[0]), "{failure}");
+        assert!(failure.original.0.len() >= failure.input.0.len());
+        assert_eq!(format!("{}\n", failure.input.0[0]), failure.expected);
+        assert!(failure.to_string().contains("--- input\n1\n"));
+    }
+
+    #[test]
+    fn reproducible() {
+        let a = run::<Seq, BuggyMax, Max>(0..200).unwrap_err();
+        let b = run::<Seq, BuggyMax, Max>(0..200).unwrap_err();
+        assert_eq!(a.seed, b.seed);
+        assert_eq!(a.input.0, b.input.0);
+    }
+
+    #[test]
+    fn panic_counts_as_mismatch() {
+        let failure = run::<Seq, Panics, Max>(0..1).unwrap_err();
+        assert!(failure.actual.starts_with("<panic: index out of bounds"));
+    }
+}
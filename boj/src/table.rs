@@ -0,0 +1,184 @@
+//! `static mut` 2차원 배열 대신 쓰는 힙 위의 표.
+//!
+//! 값은 행 우선(row-major)으로 하나의 `Vec`에 붙어 있으므로 `[[T; M]; N]`과 메모리 배치가
+//! 같고, 풀이마다 필요한 크기만큼만 잡는다. 전역 상태가 없으니 테스트를 병렬로 돌려도 된다.
+//!
+//! - `table[(i, j)]`: 범위를 벗어나면 panic
+//! - [`Table2D::get`]/[`Table2D::get_signed`]: 범위를 벗어나면 `None`
+//! - [`Table2D::get_unchecked`]: 검사하지 않는다. 가장 안쪽 반복문에서 쓴다.
+
+use std::{
+    fmt::{self, Debug},
+    ops::{Index, IndexMut},
+};
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct Table2D<T> {
+    rows: usize,
+    cols: usize,
+    data: Vec<T>,
+}
+
+impl<T: Clone> Table2D<T> {
+    /// 모든 칸을 `value`로 채운 `rows`×`cols` 표
+    pub fn new(rows: usize, cols: usize, value: T) -> Self {
+        Self {
+            rows,
+            cols,
+            data: vec![value; rows * cols],
+        }
+    }
+
+    pub fn fill(&mut self, value: T) {
+        self.data.fill(value);
+    }
+}
+
+impl<T> Table2D<T> {
+    pub fn from_fn(rows: usize, cols: usize, mut f: impl FnMut(usize, usize) -> T) -> Self {
+        let data = (0..rows)
+            .flat_map(|i| (0..cols).map(move |j| (i, j)))
+            .map(|(i, j)| f(i, j))
+            .collect();
+        Self { rows, cols, data }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    fn offset(&self, i: usize, j: usize) -> Option<usize> {
+        (i < self.rows && j < self.cols).then(|| i * self.cols + j)
+    }
+
+    pub fn get(&self, i: usize, j: usize) -> Option<&T> {
+        self.offset(i, j).map(|at| &self.data[at])
+    }
+
+    pub fn get_mut(&mut self, i: usize, j: usize) -> Option<&mut T> {
+        self.offset(i, j).map(|at| &mut self.data[at])
+    }
+
+    /// 음수 좌표도 받는다. `i - 1`처럼 이웃 칸을 볼 때 경계 검사를 따로 하지 않아도 된다.
+    pub fn get_signed(&self, i: isize, j: isize) -> Option<&T> {
+        self.get(usize::try_from(i).ok()?, usize::try_from(j).ok()?)
+    }
+
+    /// # Safety
+    /// `i < self.rows()`이고 `j < self.cols()`여야 한다.
+    pub unsafe fn get_unchecked(&self, i: usize, j: usize) -> &T {
+        debug_assert!(i < self.rows && j < self.cols);
+        self.data.get_unchecked(i * self.cols + j)
+    }
+
+    /// # Safety
+    /// `i < self.rows()`이고 `j < self.cols()`여야 한다.
+    pub unsafe fn get_unchecked_mut(&mut self, i: usize, j: usize) -> &mut T {
+        debug_assert!(i < self.rows && j < self.cols);
+        let cols = self.cols;
+        self.data.get_unchecked_mut(i * cols + j)
+    }
+
+    pub fn row(&self, i: usize) -> &[T] {
+        &self.data[i * self.cols..(i + 1) * self.cols]
+    }
+
+    pub fn row_mut(&mut self, i: usize) -> &mut [T] {
+        let cols = self.cols;
+        &mut self.data[i * cols..(i + 1) * cols]
+    }
+
+    /// 행 단위로 훑는다.
+    pub fn iter_rows(&self) -> impl Iterator<Item = &[T]> {
+        // `cols`가 0이면 `chunks`가 panic하므로 빈 행을 직접 만든다.
+        (0..self.rows).map(|i| self.row(i))
+    }
+}
+
+impl<T> Index<(usize, usize)> for Table2D<T> {
+    type Output = T;
+
+    fn index(&self, (i, j): (usize, usize)) -> &T {
+        match self.offset(i, j) {
+            Some(at) => &self.data[at],
+            None => panic!(
+                "index ({i}, {j}) out of bounds for {}x{} table",
+                self.rows, self.cols
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Table2D<T> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut T {
+        match self.offset(i, j) {
+            Some(at) => &mut self.data[at],
+            None => panic!(
+                "index ({i}, {j}) out of bounds for {}x{} table",
+                self.rows, self.cols
+            ),
+        }
+    }
+}
+
+impl<T: Debug> Debug for Table2D<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter_rows()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn index_is_row_major() {
+        let mut table = Table2D::from_fn(2, 3, |i, j| i * 10 + j);
+        assert_eq!((2, 3), (table.rows(), table.cols()));
+        assert_eq!(12, table[(1, 2)]);
+        table[(0, 1)] = 99;
+        assert_eq!(&[0, 99, 2], table.row(0));
+        assert_eq!("[[0, 99, 2], [10, 11, 12]]", format!("{table:?}"));
+    }
+
+    #[test]
+    fn checked_access() {
+        let mut table = Table2D::new(2, 3, 0);
+        assert_eq!(Some(&0), table.get(1, 2));
+        // 열이 넘치면 다음 행으로 넘어가지 않고 `None`
+        assert_eq!(None, table.get(0, 3));
+        assert_eq!(None, table.get(2, 0));
+        assert_eq!(None, table.get_signed(-1, 0));
+        assert_eq!(None, table.get_signed(0, -1));
+        assert_eq!(Some(&0), table.get_signed(1, 1));
+        *table.get_mut(1, 1).unwrap() = 5;
+        assert!(table.get_mut(1, 3).is_none());
+        assert_eq!(5, unsafe { *table.get_unchecked(1, 1) });
+    }
+
+    #[test]
+    #[should_panic(expected = "index (0, 3) out of bounds for 2x3 table")]
+    fn index_panics_on_column_overflow() {
+        let table = Table2D::new(2, 3, 0);
+        let _ = table[(0, 3)];
+    }
+
+    #[test]
+    fn fill_and_rows() {
+        let mut table = Table2D::new(3, 2, 1);
+        table.row_mut(1).fill(2);
+        assert_eq!(
+            vec![&[1, 1][..], &[2, 2], &[1, 1]],
+            table.iter_rows().collect::<Vec<_>>()
+        );
+        table.fill(7);
+        assert!(table.iter_rows().flatten().all(|&x| x == 7));
+
+        let empty: Table2D<u8> = Table2D::new(3, 0, 0);
+        assert_eq!(3, empty.iter_rows().count());
+    }
+}
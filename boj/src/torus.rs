@@ -0,0 +1,404 @@
+//! 행과 열을 통째로 돌리는 표(큐브 퍼즐처럼 끝이 처음으로 이어진다).
+//!
+//! - [`Rotate`]: 행을 오른쪽으로, 열을 아래로 돌리는 연산
+//! - [`Query`]: 값이 지금 어디 있는지 찾는 연산
+//! - [`Torus`]: 값마다 위치를 색인해 두어 `find`가 O(1)이고 되돌리기를 지원한다.
+//! - `Vec<Vec<T>>`: 한 칸씩 돌리고 전부 훑는 느린 구현. 테스트에서 기준으로 쓴다.
+//!
+//! ```
+//! use boj::torus::{Move, Query, Rotate, Torus};
+//!
+//! let mut torus = Torus::from_fn(2, 3, |i, j| i * 3 + j);
+//! torus.apply_sequence(&[Move::Row { row: 0, by: 1 }, Move::Col { col: 0, by: 1 }]);
+//! assert_eq!(vec![vec![3, 0, 1], vec![2, 4, 5]], torus.to_rows());
+//! assert_eq!(Some((1, 0)), torus.find(&2));
+//!
+//! torus.undo();
+//! assert_eq!(vec![vec![2, 0, 1], vec![3, 4, 5]], torus.to_rows());
+//! ```
+
+use std::{collections::HashMap, hash::Hash};
+
+use crate::table::Table2D;
+
+/// 한 줄을 돌리는 연산. 행은 오른쪽으로, 열은 아래로 `by`칸 돌린다.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Move {
+    Row { row: usize, by: usize },
+    Col { col: usize, by: usize },
+}
+
+impl Move {
+    /// `rows`×`cols` 표에서 이 연산을 되돌리는 연산
+    pub fn inverse(self, rows: usize, cols: usize) -> Move {
+        match self {
+            Move::Row { row, by } => Move::Row {
+                row,
+                by: back(by, cols),
+            },
+            Move::Col { col, by } => Move::Col {
+                col,
+                by: back(by, rows),
+            },
+        }
+    }
+}
+
+/// 길이 `len`인 줄을 `by`칸 돌린 것을 되돌리는 칸 수. 빈 줄이면 0.
+fn back(by: usize, len: usize) -> usize {
+    match by.checked_rem(len) {
+        None | Some(0) => 0,
+        Some(by) => len - by,
+    }
+}
+
+pub trait Rotate {
+    fn rows(&self) -> usize;
+    fn cols(&self) -> usize;
+
+    /// `row`행을 오른쪽으로 `by`칸 돌린다.
+    fn rotate_row(&mut self, row: usize, by: usize);
+
+    /// `col`열을 아래로 `by`칸 돌린다.
+    fn rotate_col(&mut self, col: usize, by: usize);
+
+    fn apply(&mut self, mv: Move) {
+        match mv {
+            Move::Row { row, by } => self.rotate_row(row, by),
+            Move::Col { col, by } => self.rotate_col(col, by),
+        }
+    }
+
+    fn apply_sequence(&mut self, moves: &[Move]) {
+        moves.iter().for_each(|&mv| self.apply(mv));
+    }
+
+    /// `from`에 있는 값을 행을 오른쪽으로 돌린 뒤 열을 아래로 돌려 `to`로 옮긴다.
+    /// 돌린 칸 수의 합을 돌려준다. (BOJ 2932)
+    ///
+    /// # Panics
+    /// `from`이나 `to`가 표 밖이면 panic한다. 빈 표에서는 언제나 panic한다.
+    fn move_to(&mut self, from: (usize, usize), to: (usize, usize)) -> usize {
+        let (rows, cols) = (self.rows(), self.cols());
+        assert!(
+            from.0 < rows && from.1 < cols && to.0 < rows && to.1 < cols,
+            "move from {from:?} to {to:?} is outside of {rows}x{cols} table"
+        );
+        let by_col = (to.1 + self.cols() - from.1) % self.cols();
+        let by_row = (to.0 + self.rows() - from.0) % self.rows();
+        self.rotate_row(from.0, by_col);
+        self.rotate_col(to.1, by_row);
+        by_col + by_row
+    }
+
+    /// `pos`에 있는 값을 `dst`로 옮기고 돌린 칸 수를 돌려준다. [`Rotate::move_to`]와 같다.
+    fn rotate(&mut self, pos: (usize, usize), dst: (usize, usize)) -> usize {
+        self.move_to(pos, dst)
+    }
+}
+
+pub trait Query {
+    type Value;
+
+    /// `value`가 있는 `(행, 열)`. 없으면 `None`.
+    fn find(&self, value: &Self::Value) -> Option<(usize, usize)>;
+
+    /// 값을 받아서 [`Query::find`]를 부른다.
+    fn get_pos(&self, value: Self::Value) -> Option<(usize, usize)>
+    where
+        Self::Value: Sized,
+    {
+        self.find(&value)
+    }
+}
+
+/// 값이 모두 다른 `rows`×`cols` 표. 돌린 기록을 남겨 [`Torus::undo`]로 되돌릴 수 있다.
+#[derive(Clone, Debug)]
+pub struct Torus<T> {
+    cells: Table2D<T>,
+    index: HashMap<T, (usize, usize)>,
+    history: Vec<Move>,
+}
+
+impl<T: Clone + Eq + Hash> Torus<T> {
+    /// # Panics
+    /// 행이나 열이 0개이거나 같은 값이 두 번 나오면 panic한다.
+    pub fn from_fn(rows: usize, cols: usize, f: impl FnMut(usize, usize) -> T) -> Self {
+        assert!(
+            rows > 0 && cols > 0,
+            "torus must not be empty: {rows}x{cols}"
+        );
+        let cells = Table2D::from_fn(rows, cols, f);
+        let mut index = HashMap::with_capacity(rows * cols);
+        for (i, row) in cells.iter_rows().enumerate() {
+            for (j, value) in row.iter().enumerate() {
+                let old = index.insert(value.clone(), (i, j));
+                assert!(old.is_none(), "duplicate value at ({i}, {j})");
+            }
+        }
+        Self {
+            cells,
+            index,
+            history: vec![],
+        }
+    }
+
+    /// # Panics
+    /// 비어 있거나, 행마다 길이가 다르거나, 같은 값이 두 번 나오면 panic한다.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let cols = rows.first().map_or(0, Vec::len);
+        assert!(
+            rows.iter().all(|row| row.len() == cols),
+            "rows have different lengths"
+        );
+        Self::from_fn(rows.len(), cols, |i, j| rows[i][j].clone())
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> &T {
+        &self.cells[(row, col)]
+    }
+
+    pub fn to_rows(&self) -> Vec<Vec<T>> {
+        self.cells.iter_rows().map(<[T]>::to_vec).collect()
+    }
+
+    /// 지금까지 한 연산. [`Torus::undo`]로 되돌린 것은 빠진다.
+    pub fn history(&self) -> &[Move] {
+        &self.history
+    }
+
+    /// 마지막 연산을 되돌리고 그 연산을 돌려준다.
+    pub fn undo(&mut self) -> Option<Move> {
+        let mv = self.history.pop()?;
+        match mv.inverse(self.rows(), self.cols()) {
+            Move::Row { row, by } => self.shift_row(row, by),
+            Move::Col { col, by } => self.shift_col(col, by),
+        }
+        Some(mv)
+    }
+
+    fn shift_row(&mut self, row: usize, by: usize) {
+        let cols = self.cols();
+        let line = self.cells.row_mut(row);
+        line.rotate_right(by % cols);
+        for (j, value) in line.iter().enumerate() {
+            self.index.insert(value.clone(), (row, j));
+        }
+    }
+
+    fn shift_col(&mut self, col: usize, by: usize) {
+        let rows = self.rows();
+        let mut line: Vec<T> = (0..rows).map(|i| self.cells[(i, col)].clone()).collect();
+        line.rotate_right(by % rows);
+        for (i, value) in line.into_iter().enumerate() {
+            self.index.insert(value.clone(), (i, col));
+            self.cells[(i, col)] = value;
+        }
+    }
+}
+
+impl<T: Clone + Eq + Hash> Rotate for Torus<T> {
+    fn rows(&self) -> usize {
+        self.cells.rows()
+    }
+
+    fn cols(&self) -> usize {
+        self.cells.cols()
+    }
+
+    fn rotate_row(&mut self, row: usize, by: usize) {
+        self.shift_row(row, by);
+        self.history.push(Move::Row { row, by });
+    }
+
+    fn rotate_col(&mut self, col: usize, by: usize) {
+        self.shift_col(col, by);
+        self.history.push(Move::Col { col, by });
+    }
+}
+
+impl<T: Clone + Eq + Hash> Query for Torus<T> {
+    type Value = T;
+
+    fn find(&self, value: &T) -> Option<(usize, usize)> {
+        self.index.get(value).copied()
+    }
+}
+
+/// 한 칸씩 `by`번 돌리는 느린 구현
+impl<T> Rotate for Vec<Vec<T>> {
+    fn rows(&self) -> usize {
+        self.len()
+    }
+
+    fn cols(&self) -> usize {
+        self.first().map_or(0, Vec::len)
+    }
+
+    fn rotate_row(&mut self, row: usize, by: usize) {
+        for _ in 0..by {
+            self[row].rotate_right(1);
+        }
+    }
+
+    fn rotate_col(&mut self, col: usize, by: usize) {
+        let rows = self.rows();
+        for _ in 0..by {
+            for i in (1..rows).rev() {
+                let (upper, lower) = self.split_at_mut(i);
+                std::mem::swap(&mut upper[i - 1][col], &mut lower[0][col]);
+            }
+        }
+    }
+}
+
+/// 행 우선으로 처음 나오는 위치
+impl<T: PartialEq> Query for Vec<Vec<T>> {
+    type Value = T;
+
+    fn find(&self, value: &T) -> Option<(usize, usize)> {
+        self.iter()
+            .enumerate()
+            .find_map(|(i, row)| row.iter().position(|cell| cell == value).map(|j| (i, j)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    use super::*;
+
+    fn random_move(rng: &mut StdRng, rows: usize, cols: usize) -> Move {
+        if rng.gen() {
+            Move::Row {
+                row: rng.gen_range(0..rows),
+                by: rng.gen_range(0..cols * 2),
+            }
+        } else {
+            Move::Col {
+                col: rng.gen_range(0..cols),
+                by: rng.gen_range(0..rows * 2),
+            }
+        }
+    }
+
+    #[test]
+    fn brute_force_single_steps() {
+        let mut table = vec![vec![1, 2, 3], vec![4, 5, 6]];
+        table.rotate_row(0, 1);
+        assert_eq!(vec![vec![3, 1, 2], vec![4, 5, 6]], table);
+        table.rotate_col(2, 3);
+        assert_eq!(vec![vec![3, 1, 6], vec![4, 5, 2]], table);
+        assert_eq!(Some((1, 2)), table.find(&2));
+        assert_eq!(None, table.find(&7));
+    }
+
+    #[test]
+    fn same_as_brute_force() {
+        let mut rng = StdRng::seed_from_u64(47);
+        for _ in 0..200 {
+            let (rows, cols) = (rng.gen_range(1..=6), rng.gen_range(1..=6));
+            let mut oracle: Vec<Vec<usize>> = (0..rows)
+                .map(|i| (0..cols).map(|j| i * cols + j).collect())
+                .collect();
+            let mut torus = Torus::from_rows(oracle.clone());
+
+            let moves: Vec<_> = (0..20).map(|_| random_move(&mut rng, rows, cols)).collect();
+            torus.apply_sequence(&moves);
+            oracle.apply_sequence(&moves);
+            assert_eq!(oracle, torus.to_rows(), "{moves:?}");
+            for value in 0..rows * cols {
+                assert_eq!(oracle.find(&value), torus.find(&value));
+            }
+
+            let value = rng.gen_range(0..rows * cols);
+            let to = (rng.gen_range(0..rows), rng.gen_range(0..cols));
+            let expected = oracle.move_to(oracle.find(&value).unwrap(), to);
+            assert_eq!(expected, torus.move_to(torus.find(&value).unwrap(), to));
+            assert_eq!(Some(to), torus.find(&value));
+            assert_eq!(oracle, torus.to_rows());
+        }
+    }
+
+    #[test]
+    fn undo_restores_every_state() {
+        let mut rng = StdRng::seed_from_u64(48);
+        let mut torus = Torus::from_fn(4, 5, |i, j| (i, j));
+        let mut states = vec![torus.to_rows()];
+        for _ in 0..30 {
+            torus.apply(random_move(&mut rng, 4, 5));
+            states.push(torus.to_rows());
+        }
+        assert_eq!(30, torus.history().len());
+
+        while let Some(state) = states.pop() {
+            assert_eq!(state, torus.to_rows());
+            for (i, row) in state.iter().enumerate() {
+                for (j, value) in row.iter().enumerate() {
+                    assert_eq!(Some((i, j)), torus.find(value));
+                }
+            }
+            torus.undo();
+        }
+        assert_eq!(None, torus.undo());
+    }
+
+    #[test]
+    fn inverse() {
+        assert_eq!(
+            Move::Row { row: 1, by: 2 },
+            Move::Row { row: 1, by: 8 }.inverse(4, 5)
+        );
+        assert_eq!(
+            Move::Col { col: 0, by: 0 },
+            Move::Col { col: 0, by: 4 }.inverse(4, 5)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "torus must not be empty: 0x3")]
+    fn empty_torus() {
+        Torus::from_fn(0, 3, |i, j| (i, j));
+    }
+
+    #[test]
+    #[should_panic(expected = "torus must not be empty: 2x0")]
+    fn torus_without_columns() {
+        Torus::<i32>::from_rows(vec![vec![], vec![]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "move from (0, 0) to (0, 0) is outside of 2x0 table")]
+    fn move_in_table_without_columns() {
+        let mut table: Vec<Vec<i32>> = vec![vec![], vec![]];
+        assert_eq!(None, table.find(&0));
+        table.move_to((0, 0), (0, 0));
+    }
+
+    #[test]
+    fn inverse_in_empty_table() {
+        assert_eq!(
+            Move::Row { row: 0, by: 0 },
+            Move::Row { row: 0, by: 3 }.inverse(2, 0)
+        );
+        assert_eq!(
+            Move::Col { col: 0, by: 0 },
+            Move::Col { col: 0, by: 3 }.inverse(0, 2)
+        );
+    }
+
+    #[test]
+    fn rotate_and_get_pos() {
+        let mut torus = Torus::from_fn(3, 3, |i, j| i * 3 + j);
+        let pos = torus.get_pos(1).unwrap();
+        assert_eq!(3, torus.rotate(pos, (2, 2)));
+        assert_eq!(Some((2, 2)), torus.get_pos(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "duplicate value at (1, 0)")]
+    fn duplicate_values() {
+        Torus::from_rows(vec![vec![1, 2], vec![1, 3]]);
+    }
+}
@@ -0,0 +1,240 @@
+//! 문제의 예제 입출력으로 `boj run <problem>` 풀이를 검사한다.
+//!
+//! `tests/data/<problem>/<n>.in`을 표준 입력으로 넣고 `tests/data/<problem>/<n>.out`과 비교한다.
+//! 새 문제를 추가할 때는 예제 파일만 넣으면 된다.
+//!
+//! - 같은 예제를 프로세스를 띄우지 않고 [`boj::problems`]의 풀이에 직접 넣어서도 검사한다.
+//! - 채점 서버처럼 줄 끝 공백과 마지막 빈 줄은 무시한다.
+//! - `BOJ_PROBLEM=11660 cargo test -p boj --test golden -- --nocapture`처럼 문제를 골라
+//!   돌릴 수 있고, `--nocapture`를 주면 케이스마다 걸린 시간을 보여 준다.
+
+use boj::problems;
+use std::{
+    env, fs,
+    io::Write,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    thread,
+    time::{Duration, Instant},
+};
+
+/// 틀린 줄을 이만큼까지만 보여 준다.
+const MAX_DIFF_LINES: usize = 10;
+
+struct Case {
+    problem: String,
+    name: String,
+    input: PathBuf,
+    expected: PathBuf,
+}
+
+enum Verdict {
+    Accepted,
+    WrongAnswer(String),
+    RuntimeError(String),
+}
+
+fn data_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("data")
+}
+
+/// cargo가 통합 테스트를 빌드할 때 알려 주는 `src/bin/boj.rs`의 경로
+const BOJ_BIN: &str = env!("CARGO_BIN_EXE_boj");
+
+/// `1.in`, `2.in`, ..., `10.in` 순서가 되도록 숫자로 정렬한다.
+fn case_order(name: &str) -> (u64, String) {
+    (name.parse().unwrap_or(u64::MAX), name.to_owned())
+}
+
+fn discover(filter: Option<&str>) -> Vec<Case> {
+    let mut cases = vec![];
+    let mut problems: Vec<_> = fs::read_dir(data_dir())
+        .expect("tests/data is missing")
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.is_dir())
+        .collect();
+    problems.sort();
+
+    for dir in problems {
+        let problem = dir.file_name().unwrap().to_string_lossy().into_owned();
+        if filter.is_some_and(|filter| filter != problem) {
+            continue;
+        }
+        let mut inputs: Vec<_> = fs::read_dir(&dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "in"))
+            .collect();
+        inputs.sort_by_key(|path| case_order(&path.file_stem().unwrap().to_string_lossy()));
+
+        for input in inputs {
+            let name = input.file_stem().unwrap().to_string_lossy().into_owned();
+            let expected = input.with_extension("out");
+            assert!(
+                expected.exists(),
+                "{} has no matching .out file",
+                input.display()
+            );
+            cases.push(Case {
+                problem: problem.clone(),
+                name,
+                input,
+                expected,
+            });
+        }
+    }
+    cases
+}
+
+/// 줄 끝 공백과 끝에 붙은 빈 줄을 지운다. `\r\n`도 `\n`으로 취급한다.
+fn normalize(text: &str) -> Vec<&str> {
+    let mut lines: Vec<&str> = text.lines().map(str::trim_end).collect();
+    while lines.last().is_some_and(|line| line.is_empty()) {
+        lines.pop();
+    }
+    lines
+}
+
+/// 다른 줄만 `-기대값`/`+실제값`으로 보여 준다.
+fn diff(expected: &[&str], actual: &[&str]) -> String {
+    let mut report = String::new();
+    let mut shown = 0;
+    for i in 0..expected.len().max(actual.len()) {
+        let (want, got) = (expected.get(i), actual.get(i));
+        if want == got {
+            continue;
+        }
+        if shown == MAX_DIFF_LINES {
+            report.push_str("    ...\n");
+            break;
+        }
+        shown += 1;
+        report.push_str(&format!("    line {}:\n", i + 1));
+        report.push_str(&format!("    - {}\n", want.unwrap_or(&"<missing>")));
+        report.push_str(&format!("    + {}\n", got.unwrap_or(&"<missing>")));
+    }
+    report
+}
+
+fn judge(case: &Case, actual: &[u8]) -> Verdict {
+    let expected = fs::read_to_string(&case.expected).unwrap();
+    let actual = String::from_utf8_lossy(actual);
+    let (expected, actual) = (normalize(&expected), normalize(&actual));
+    if expected == actual {
+        Verdict::Accepted
+    } else {
+        Verdict::WrongAnswer(diff(&expected, &actual))
+    }
+}
+
+/// `boj run <problem>` 프로세스를 띄워서 푼다.
+fn run_process(case: &Case) -> (Verdict, Duration) {
+    let input = fs::read(&case.input).unwrap();
+
+    let start = Instant::now();
+    let mut child = Command::new(BOJ_BIN)
+        .args(["run", &case.problem])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap_or_else(|err| panic!("cannot run {BOJ_BIN}: {err}"));
+    // 출력이 큰 경우 파이프가 막히지 않도록 입력은 다른 스레드에서 넣는다.
+    let mut stdin = child.stdin.take().unwrap();
+    let writer = thread::spawn(move || {
+        // 풀이가 입력을 다 읽지 않고 끝나면 broken pipe가 나는데, 그건 괜찮다.
+        let _ = stdin.write_all(&input);
+    });
+    let output = child.wait_with_output().unwrap();
+    let elapsed = start.elapsed();
+    writer.join().unwrap();
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return (
+            Verdict::RuntimeError(format!("    {}\n    {}", output.status, stderr.trim())),
+            elapsed,
+        );
+    }
+    (judge(case, &output.stdout), elapsed)
+}
+
+/// [`boj::problems::find`]로 찾은 풀이를 같은 프로세스 안에서 부른다.
+fn run_in_process(case: &Case) -> (Verdict, Duration) {
+    let solve = problems::find(&case.problem).unwrap_or_else(|| {
+        panic!(
+            "tests/data/{} is not registered in boj::problems",
+            case.problem
+        )
+    });
+    let input = fs::read(&case.input).unwrap();
+    let mut output = vec![];
+
+    let start = Instant::now();
+    let result = solve(&mut &input[..], &mut output);
+    let elapsed = start.elapsed();
+
+    match result {
+        Ok(()) => (judge(case, &output), elapsed),
+        Err(err) => (Verdict::RuntimeError(format!("    {err}")), elapsed),
+    }
+}
+
+fn check_all(run: fn(&Case) -> (Verdict, Duration)) {
+    let filter = env::var("BOJ_PROBLEM").ok();
+    let cases = discover(filter.as_deref());
+    assert!(!cases.is_empty(), "no test cases found for {filter:?}");
+
+    let mut failures = vec![];
+    for case in &cases {
+        let (verdict, elapsed) = run(case);
+        let id = format!("{}/{}", case.problem, case.name);
+        let ms = elapsed.as_secs_f64() * 1000.0;
+        match verdict {
+            Verdict::Accepted => println!("  ok {id:<12} {ms:>8.1}ms"),
+            Verdict::WrongAnswer(diff) => {
+                println!("  WA {id:<12} {ms:>8.1}ms");
+                failures.push(format!("{id}: wrong answer\n{diff}"));
+            }
+            Verdict::RuntimeError(msg) => {
+                println!("  RE {id:<12} {ms:>8.1}ms");
+                failures.push(format!("{id}: runtime error\n{msg}"));
+            }
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "{} of {} cases failed\n{}",
+        failures.len(),
+        cases.len(),
+        failures.join("\n")
+    );
+}
+
+#[test]
+fn golden() {
+    check_all(run_process);
+}
+
+#[test]
+fn golden_in_process() {
+    check_all(run_in_process);
+}
+
+#[test]
+fn normalize_ignores_trailing_whitespace() {
+    assert_eq!(vec!["1 2", "", "3"], normalize("1 2 \r\n\n3\t\n\n\n"));
+    assert_eq!(Vec::<&str>::new(), normalize("\n \n"));
+}
+
+#[test]
+fn diff_shows_only_mismatched_lines() {
+    let report = diff(&["1", "2", "3"], &["1", "5"]);
+    assert_eq!(
+        "    line 2:\n    - 2\n    + 5\n    line 3:\n    - 3\n    + <missing>\n",
+        report
+    );
+}
@@ -0,0 +1,17 @@
+//! `cargo run -p self_ref --example step_001_ouroboros`
+use std::thread;
+
+use self_ref::OwnedTokens;
+
+fn main() {
+    let tokens = OwnedTokens::tokenize("let answer = 42 ;");
+    println!("{:?}", tokens);
+    println!("{} tokens, first = {:?}", tokens.len(), tokens.get(0));
+
+    // 입력 버퍼와 그걸 빌린 토큰들을 통째로 다른 스레드로 보낼 수 있다.
+    let mut tokens = thread::spawn(move || tokens).join().unwrap();
+    tokens.retain(|token| token.chars().all(char::is_alphanumeric));
+    println!("after retain: {:?}", tokens.borrow_tokens());
+
+    println!("original input: {:?}", tokens.into_input());
+}
@@ -0,0 +1,24 @@
+//! `cargo run -p self_ref --example step_002_raw_pointers`
+use self_ref::{step_002_raw_pointers::Test, MovableTest};
+
+fn main() {
+    let test1 = Test::new("test1");
+    println!("Test: value = {}", test1.get_value());
+    println!(
+        "  once `Test` is moved, `pointer_to_value` dangles and `get_pointer_to_value()` panics."
+    );
+    println!("  (see `moved_pointer_panics` in src/step_002_raw_pointers.rs)");
+
+    let mut a = MovableTest::new("a");
+    let mut b = MovableTest::new("b");
+    std::mem::swap(&mut a, &mut b);
+    println!(
+        "MovableTest after swap: a -> {}, b -> {}",
+        a.get_pointer_to_value(),
+        b.get_pointer_to_value()
+    );
+
+    let many: Vec<_> = (0..5).map(|i| MovableTest::new(&i.to_string())).collect();
+    let resolved: Vec<_> = many.iter().map(|t| t.get_pointer_to_value()).collect();
+    println!("MovableTest inside a Vec: {:?}", resolved);
+}
@@ -0,0 +1,22 @@
+//! `cargo run -p self_ref --example step_003_box_pin`
+use self_ref::step_003_box_pin::Test;
+
+fn main() {
+    let mut test1 = Test::new("test1");
+    let mut test2 = Test::new("test2");
+
+    // `Pin<Box<_>>`끼리 바꾸면 힙에 있는 내용물은 그대로이므로 포인터도 멀쩡하다.
+    std::mem::swap(&mut test1, &mut test2);
+    println!(
+        "after swapping boxes: test1 = {} / {}",
+        test1.as_ref().get_value(),
+        test1.as_ref().get_pointer_to_value()
+    );
+    println!(
+        "                      test2 = {} / {}",
+        test2.as_ref().get_value(),
+        test2.as_ref().get_pointer_to_value()
+    );
+    println!("but `Test` is still `Unpin`, so `get_mut()` lets us move the contents out.");
+    println!("see step_004_box_phantompin for the fix.");
+}
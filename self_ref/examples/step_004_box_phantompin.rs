@@ -0,0 +1,19 @@
+//! `cargo run -p self_ref --example step_004_box_phantompin`
+use self_ref::PinnedTest;
+
+fn main() {
+    let mut test = PinnedTest::new("hello");
+    println!(
+        "value = {}, pointer_to_value = {}",
+        test.as_ref().get_value(),
+        test.as_ref().get_pointer_to_value()
+    );
+
+    // `PhantomPinned` 덕분에 `get_mut()`은 컴파일되지 않지만, projection으로는 바꿀 수 있다.
+    test.as_mut().set_value("world");
+    println!(
+        "value = {}, pointer_to_value = {}",
+        test.as_ref().get_value(),
+        test.as_ref().get_pointer_to_value()
+    );
+}
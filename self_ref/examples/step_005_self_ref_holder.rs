@@ -0,0 +1,25 @@
+//! `cargo run -p self_ref --example step_005_self_ref_holder`
+use std::rc::Rc;
+
+use self_ref::step_005_self_ref_holder::{Holder, Me};
+
+fn main() {
+    let holder = Holder::new();
+    let a = Me::new(Rc::clone(&holder), "a");
+    let b = Me::new(Rc::clone(&holder), "b");
+
+    for val in 1..=3 {
+        holder.borrow().mutate_value_of_me(val);
+        println!(
+            "broadcast {val}: {} = {}, {} = {}",
+            a.name(),
+            a.as_ref().get_value(),
+            b.name(),
+            b.as_ref().get_value()
+        );
+    }
+
+    drop(a);
+    println!("after dropping a: holder = {:?}", holder.borrow());
+    drop(b);
+}
@@ -0,0 +1,27 @@
+//! `cargo run -p self_ref --example step_006_self_ref_cmp`
+use std::rc::Rc;
+
+use self_ref::{Holder, Me};
+
+fn main() {
+    let holder = Holder::new();
+    let mes: Vec<_> = ["b", "a", "b"]
+        .into_iter()
+        .map(|name| Me::new(Rc::clone(&holder), name))
+        .collect();
+    println!(
+        "registered {} `Me`s (duplicated names included)",
+        holder.borrow().len()
+    );
+
+    holder.borrow().mutate_value_of_me(10);
+    for me in &mes {
+        println!("  {} -> {}", me.name(), me.get_value());
+    }
+
+    let mut mes = mes;
+    let dropped = mes.remove(0);
+    println!("dropping the first \"{}\"", dropped.name());
+    drop(dropped);
+    println!("holder now has {} `Me`s", holder.borrow().len());
+}
@@ -0,0 +1,15 @@
+//! `cargo run -p self_ref --example step_007_linkedlist`
+use self_ref::MyLinkedList;
+
+fn main() {
+    let mut list = MyLinkedList::new();
+    for i in 1..=3 {
+        list.push_back(i);
+        list.push_front(-i);
+        println!("{:?}", list);
+    }
+
+    while let (Some(front), Some(back)) = (list.pop_front(), list.pop_back()) {
+        println!("popped {front} and {back}, left {:?}", list);
+    }
+}
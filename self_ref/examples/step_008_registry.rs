@@ -0,0 +1,34 @@
+//! `cargo run -p self_ref --example step_008_registry`
+use std::{pin::Pin, thread};
+
+use self_ref::{Registry, SyncRegistry};
+
+fn main() {
+    let registry = Registry::new();
+    let x = registry.register("x", Box::pin(0));
+    let y = registry.register("x", Box::pin(100)); // same key, still kept
+    registry.for_each_mut(|key, mut val: Pin<&mut i32>| {
+        *val += 1;
+        println!("visited {key}: {val}");
+    });
+    println!("keys = {:?}", registry.keys());
+    drop(x);
+    println!(
+        "after dropping one: keys = {:?}, y = {}",
+        registry.keys(),
+        *y.borrow()
+    );
+
+    let registry = SyncRegistry::new();
+    let counters: Vec<_> = (0..3).map(|i| registry.register(i, Box::pin(0))).collect();
+    let workers: Vec<_> = (0..4)
+        .map(|_| {
+            let registry = registry.clone();
+            thread::spawn(move || registry.for_each_mut(|_, mut val| *val += 1))
+        })
+        .collect();
+    workers.into_iter().for_each(|w| w.join().unwrap());
+    for counter in &counters {
+        println!("counter {} = {}", counter.key(), counter.with(|val| *val));
+    }
+}
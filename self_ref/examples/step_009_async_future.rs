@@ -0,0 +1,15 @@
+//! `cargo run -p self_ref --example step_009_async_future`
+use self_ref::{block_on, CountWords};
+
+fn main() {
+    let input = "futures are self referential state machines";
+    let count = block_on(CountWords::new(input));
+    println!("{input:?} has {count} words");
+
+    let total = block_on(async {
+        let a = CountWords::new("one two three").await;
+        let b = CountWords::new("four five").await;
+        a + b
+    });
+    println!("nested in an async block: {total}");
+}
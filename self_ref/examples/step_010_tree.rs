@@ -0,0 +1,29 @@
+//! `cargo run -p self_ref --example step_010_tree`
+use self_ref::TreeNode;
+
+fn main() {
+    let mut config = TreeNode::new("config");
+    let mut server = config.as_mut().push_child("server");
+    server.as_mut().push_child("port");
+    server.as_mut().push_child("host");
+    config.as_mut().push_child("logging").push_child("level");
+
+    println!("depth-first:");
+    for node in config.dfs() {
+        println!("{}{}", "  ".repeat(node.depth()), node.value());
+    }
+
+    let bfs: Vec<_> = config.bfs().map(|node| *node.value()).collect();
+    println!("breadth-first: {:?}", bfs);
+
+    let port = config.child(0).and_then(|server| server.child(0)).unwrap();
+    let path: Vec<_> = port.ancestors().map(|node| *node.value()).collect();
+    println!("ancestors of {}: {:?}", port.value(), path);
+
+    let logging = config.as_mut().detach(1).unwrap();
+    println!("detached {} (root? {})", logging.value(), logging.is_root());
+    println!(
+        "left: {:?}",
+        config.dfs().map(|n| *n.value()).collect::<Vec<_>>()
+    );
+}
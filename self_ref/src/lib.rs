@@ -0,0 +1,44 @@
+//! studying self referential structs in rust with
+//! <https://dev.to/arunanshub/self-referential-structs-in-rust-33cm>
+//!
+//! 각 `step_00x` 모듈이 실습 한 단계씩을 담고 있고, `examples/`에 단계별로 출력을 찍어 보는
+//! 예제가 있다. (`cargo run -p self_ref --example step_001_ouroboros`)
+//!
+//! 다른 crate에서 쓸 만한 것들은 crate root로 다시 내보낸다.
+//!
+//! | 무엇 | 어디서 |
+//! |---|---|
+//! | ouroboros로 만든 토큰 버퍼 | [`OwnedTokens`] |
+//! | move해도 안전한 상대 포인터 | [`RelPtr`], [`MovableTest`] |
+//! | `PhantomPinned`으로 고정된 `Test` | [`PinnedTest`] |
+//! | `Holder`/`Me` 레지스트리 | [`Holder`], [`Me`], [`Registry`], [`SyncRegistry`] |
+//! | pinned sentinel 연결 리스트 | [`MyLinkedList`] |
+//! | 손으로 만든 future와 executor | [`CountWords`], [`block_on`] |
+//! | 부모 포인터가 있는 트리 | [`TreeNode`] |
+//! | pin projection 매크로 | [`pin_project!`] |
+
+#[macro_use]
+mod pin_project;
+
+pub mod step_001_use_ouroboros;
+pub mod step_002_raw_pointers;
+pub mod step_003_box_pin;
+pub mod step_004_box_phantompin;
+pub mod step_005_self_ref_holder;
+pub mod step_006_self_ref_cmp;
+pub mod step_007_linkedlist;
+pub mod step_008_registry;
+pub mod step_009_async_future;
+pub mod step_010_tree;
+
+#[macro_use]
+extern crate derivative;
+
+pub use step_001_use_ouroboros::OwnedTokens;
+pub use step_002_raw_pointers::{MovableTest, RelPtr};
+pub use step_004_box_phantompin::Test as PinnedTest;
+pub use step_006_self_ref_cmp::{Holder, Me};
+pub use step_007_linkedlist::MyLinkedList;
+pub use step_008_registry::{Registration, Registry, SyncRegistration, SyncRegistry};
+pub use step_009_async_future::{block_on, CountWords};
+pub use step_010_tree::TreeNode;
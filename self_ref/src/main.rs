@@ -1,12 +0,0 @@
-mod step_001_use_ouroboros;
-mod step_002_raw_pointers;
-mod step_003_box_pin;
-mod step_004_box_phantompin;
-mod step_005_self_ref_holder;
-mod step_006_self_ref_cmp;
-mod step_007_linkedlist;
-
-#[macro_use]
-extern crate derivative;
-
-fn main() {}
@@ -0,0 +1,198 @@
+/// `pin-project-lite`를 흉내 낸 작은 projection 매크로.
+///
+/// `Pin<&mut Struct>`에서 필드에 접근하려면 매번 `get_unchecked_mut`를 `unsafe`로 불러야
+/// 했다. 이 매크로는 구조체 정의를 그대로 받아서 `project()` 메서드를 만들어 준다.
+/// `#[pin]`이 붙은 필드는 `Pin<&mut Field>`로, 나머지 필드는 `&mut Field`로 돌려준다.
+///
+/// ```
+/// use std::{marker::PhantomPinned, pin::Pin};
+///
+/// self_ref::pin_project! {
+///     #[derive(Debug)]
+///     pub struct Test {
+///         value: String,
+///         #[pin]
+///         _pinned: PhantomPinned,
+///     }
+///     project = TestProj;
+/// }
+///
+/// fn set(this: Pin<&mut Test>, value: String) {
+///     *this.project().value = value; // no unsafe!
+/// }
+///
+/// let mut test = Box::pin(Test {
+///     value: "before".to_owned(),
+///     _pinned: PhantomPinned,
+/// });
+/// set(test.as_mut(), "after".to_owned());
+/// assert_eq!("after", test.value);
+/// ```
+///
+/// `project()`가 safe하려면 고정된 필드가 move되지 않아야 하므로 매크로가 두 가지를 막는다.
+///
+/// - `Unpin`은 `#[pin]` 필드가 모두 `Unpin`일 때만 구현된다. 손으로 `impl Unpin`하면
+///   매크로가 만든 구현과 겹쳐 컴파일되지 않는다.
+///
+///   ```compile_fail
+///   use std::marker::PhantomPinned;
+///
+///   self_ref::pin_project! {
+///       pub struct Test {
+///           #[pin]
+///           _pinned: PhantomPinned,
+///       }
+///       project = TestProj;
+///   }
+///
+///   impl Unpin for Test {}
+///   ```
+///
+/// - `Drop`을 직접 구현할 수 없다. `&mut self`로는 `#[pin]` 필드를 move할 수 있기 때문이다.
+///
+///   ```compile_fail
+///   use std::marker::PhantomPinned;
+///
+///   self_ref::pin_project! {
+///       pub struct Test {
+///           #[pin]
+///           _pinned: PhantomPinned,
+///       }
+///       project = TestProj;
+///   }
+///
+///   impl Drop for Test {
+///       fn drop(&mut self) {}
+///   }
+///   ```
+///
+///   대신 매크로 안에 `PinnedDrop`을 쓰면 `Pin<&mut Self>`를 받는 `drop`을 만들어 준다.
+///
+///   ```
+///   use std::{cell::Cell, marker::PhantomPinned, pin::Pin, rc::Rc};
+///
+///   self_ref::pin_project! {
+///       pub struct Test {
+///           dropped: Rc<Cell<bool>>,
+///           #[pin]
+///           _pinned: PhantomPinned,
+///       }
+///       project = TestProj;
+///       impl PinnedDrop for Test {
+///           fn drop(this: Pin<&mut Self>) {
+///               this.project().dropped.set(true);
+///           }
+///       }
+///   }
+///
+///   let dropped = Rc::new(Cell::new(false));
+///   drop(Box::pin(Test { dropped: dropped.clone(), _pinned: PhantomPinned }));
+///   assert!(dropped.get());
+///   ```
+///
+/// `#[repr(packed)]` 구조체는 필드의 `&mut`를 만들 수 없으므로 `project()`가 컴파일되지 않는다.
+#[macro_export]
+macro_rules! pin_project {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident {
+            $(
+                $(#[$pin:ident])?
+                $field_vis:vis $field:ident : $ty:ty
+            ),* $(,)?
+        }
+        project = $proj:ident;
+        $(
+            impl PinnedDrop for $drop_name:ident {
+                fn drop($this:ident : Pin<&mut Self>) $drop_body:block
+            }
+        )?
+    ) => {
+        $(#[$attr])*
+        $vis struct $name {
+            $(
+                $field_vis $field: $ty,
+            )*
+        }
+
+        /// projection of pinned struct
+        #[allow(dead_code)]
+        $vis struct $proj<'__pin> {
+            $(
+                $field_vis $field: $crate::pin_project!(@type $(#[$pin])? '__pin, $ty),
+            )*
+        }
+
+        #[allow(dead_code)]
+        impl $name {
+            $vis fn project(self: ::std::pin::Pin<&mut Self>) -> $proj<'_> {
+                // SAFETY: `#[pin]` 필드는 `Pin`으로만 꺼내고, 나머지 필드는 구조적으로
+                // 고정되지 않으므로 `&mut`로 꺼내도 고정 보장이 깨지지 않는다. `Unpin`과
+                // `Drop`은 아래에서 막아 두었다.
+                let this = unsafe { self.get_unchecked_mut() };
+                $proj {
+                    $(
+                        $field: $crate::pin_project!(@expr $(#[$pin])? &mut this.$field),
+                    )*
+                }
+            }
+        }
+
+        // `#[pin]` 필드만 모은 구조체가 `Unpin`일 때만 `Unpin`이다. 사용자가 `Unpin`을
+        // 구현하면 이 구현과 겹친다.
+        #[allow(dead_code, non_snake_case)]
+        const _: () = {
+            struct __Origin<'__pin> {
+                __lifetime: ::std::marker::PhantomData<&'__pin ()>,
+                $(
+                    $field: $crate::pin_project!(@origin $(#[$pin])? $ty),
+                )*
+            }
+
+            impl<'__pin> ::std::marker::Unpin for $name where __Origin<'__pin>: ::std::marker::Unpin {}
+        };
+
+        $crate::pin_project!(@drop $name $(, $this, $drop_body)?);
+    };
+
+    (@type #[pin] $lt:lifetime, $ty:ty) => {
+        ::std::pin::Pin<&$lt mut $ty>
+    };
+    (@type $lt:lifetime, $ty:ty) => {
+        &$lt mut $ty
+    };
+
+    (@expr #[pin] $place:expr) => {
+        unsafe { ::std::pin::Pin::new_unchecked($place) }
+    };
+    (@expr $place:expr) => {
+        $place
+    };
+
+    (@origin #[pin] $ty:ty) => {
+        $ty
+    };
+    (@origin $ty:ty) => {
+        ()
+    };
+
+    // 모든 `Drop` 구현 타입에 대한 blanket impl과 겹치게 해서 `impl Drop`을 막는다.
+    (@drop $name:ident) => {
+        #[allow(dead_code)]
+        const _: () = {
+            trait MustNotImplDrop {}
+            #[allow(drop_bounds)]
+            impl<T: ::std::ops::Drop> MustNotImplDrop for T {}
+            impl MustNotImplDrop for $name {}
+        };
+    };
+    (@drop $name:ident, $this:ident, $body:block) => {
+        impl ::std::ops::Drop for $name {
+            fn drop(&mut self) {
+                fn __drop_inner($this: ::std::pin::Pin<&mut $name>) $body
+                // SAFETY: drop된 뒤에는 아무도 이 값을 쓰지 않으므로 여기서 다시 고정해도 된다.
+                __drop_inner(unsafe { ::std::pin::Pin::new_unchecked(self) });
+            }
+        }
+    };
+}
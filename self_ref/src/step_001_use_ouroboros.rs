@@ -10,10 +10,78 @@ struct MyStruct {
     float_reference: &'this mut f32,
 }
 
+/// 입력 버퍼(`String`)를 소유하면서, 그 버퍼를 빌려 쓰는 토큰 슬라이스들을 함께 들고
+/// 다니는 구조체. `MyStruct`의 실험을 실제로 쓸 만한 모양으로 일반화했다.
+///
+/// 토큰은 매번 새로 할당하지 않고 `input`의 부분 문자열(`&'this str`)로만 존재하기
+/// 때문에, 구조체 자체를 함수 사이나 스레드 사이로 move해도 안전하다. (ouroboros가
+/// 소유 필드를 힙에 올려두므로 move해도 주소가 바뀌지 않는다.)
+#[self_referencing]
+pub struct OwnedTokens {
+    pub input: String,
+    #[borrows(input)]
+    #[covariant]
+    pub tokens: Vec<&'this str>,
+}
+
+impl OwnedTokens {
+    /// 공백 문자를 기준으로 `input`을 토큰으로 나눈다.
+    pub fn tokenize(input: impl Into<String>) -> Self {
+        OwnedTokens::new(input.into(), |input| input.split_whitespace().collect())
+    }
+
+    /// `delimiter`를 기준으로 `input`을 토큰으로 나눈다. 빈 토큰은 버린다.
+    pub fn tokenize_by(input: impl Into<String>, delimiter: char) -> Self {
+        OwnedTokens::new(input.into(), |input| {
+            input
+                .split(delimiter)
+                .filter(|token| !token.is_empty())
+                .collect()
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.borrow_tokens().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.borrow_tokens().is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Option<&str> {
+        self.borrow_tokens().get(index).copied()
+    }
+
+    /// `pred`가 `false`를 반환하는 토큰을 제거한다. 원본 `input`은 건드리지 않는다.
+    pub fn retain<F>(&mut self, mut pred: F)
+    where
+        F: FnMut(&str) -> bool,
+    {
+        self.with_tokens_mut(|tokens| tokens.retain(|token| pred(token)));
+    }
+
+    /// 토큰들을 버리고 원본 입력 버퍼를 돌려받는다.
+    pub fn into_input(self) -> String {
+        self.into_heads().input
+    }
+}
+
+impl std::fmt::Debug for OwnedTokens {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.with(|fields| {
+            f.debug_struct("OwnedTokens")
+                .field("input", fields.input)
+                .field("tokens", fields.tokens)
+                .finish()
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     #[test]
+    #[allow(clippy::approx_constant)]
     fn run() {
         let mut my_value = MyStructBuilder {
             int_data: 42,
@@ -42,4 +110,90 @@ mod tests {
         assert_eq!(42, (*int_ref).clone());
         drop(my_value);
     }
+
+    /// 다른 함수에서 만들어 반환하고, 다시 다른 함수로 넘겨도 토큰이 유효해야 한다.
+    fn make_tokens() -> OwnedTokens {
+        let input = String::from("3 4\n1 2 5");
+        OwnedTokens::tokenize(input)
+    }
+
+    fn sum_tokens(tokens: OwnedTokens) -> i32 {
+        tokens.with_tokens(|tokens| tokens.iter().map(|t| t.parse::<i32>().unwrap()).sum())
+    }
+
+    #[test]
+    fn owned_tokens_tokenize() {
+        let tokens = OwnedTokens::tokenize("  hello   self\treferential world ");
+        assert_eq!(4, tokens.len());
+        assert_eq!(Some("hello"), tokens.get(0));
+        assert_eq!(Some("world"), tokens.get(3));
+        assert_eq!(None, tokens.get(4));
+
+        let tokens = OwnedTokens::tokenize_by("a,,b,c,", ',');
+        assert_eq!(vec!["a", "b", "c"], *tokens.borrow_tokens());
+
+        assert!(OwnedTokens::tokenize(" \n ").is_empty());
+    }
+
+    #[test]
+    fn owned_tokens_borrow_input() {
+        let tokens = OwnedTokens::tokenize("foo bar");
+        tokens.with(|fields| {
+            // 토큰은 복사본이 아니라 input 버퍼 안을 가리키고 있어야 한다.
+            let range = fields.input.as_bytes().as_ptr_range();
+            for token in fields.tokens.iter() {
+                assert!(range.contains(&token.as_ptr()));
+            }
+        });
+    }
+
+    #[test]
+    fn owned_tokens_move_across_functions() {
+        let tokens = make_tokens();
+        let moved = tokens; // move once more
+        assert_eq!(15, sum_tokens(moved));
+
+        let boxed = Box::new(make_tokens());
+        let mut vec = vec![*boxed];
+        vec.reserve(100); // reallocation moves the struct as well
+        assert_eq!(Some("5"), vec[0].get(4));
+    }
+
+    #[test]
+    fn owned_tokens_move_across_threads() {
+        let tokens = OwnedTokens::tokenize("1 2 3 4");
+        let handle = std::thread::spawn(move || {
+            assert_eq!(Some("4"), tokens.get(3));
+            tokens
+        });
+        let tokens = handle.join().unwrap();
+        assert_eq!(10, sum_tokens(tokens));
+    }
+
+    #[test]
+    fn owned_tokens_with_mut() {
+        let mut tokens = OwnedTokens::tokenize("keep drop keep drop");
+        tokens.retain(|token| token != "drop");
+        assert_eq!(vec!["keep", "keep"], *tokens.borrow_tokens());
+
+        // 토큰을 input의 다른 부분으로 갈아끼우는 것도 가능하다.
+        tokens.with_mut(|fields| {
+            fields.tokens.clear();
+            fields
+                .tokens
+                .extend(fields.input.split(' ').skip(1).step_by(2));
+        });
+        assert_eq!(vec!["drop", "drop"], *tokens.borrow_tokens());
+    }
+
+    #[test]
+    fn owned_tokens_into_heads() {
+        let mut tokens = OwnedTokens::tokenize("a b c");
+        tokens.retain(|_| false);
+        assert!(tokens.is_empty());
+        assert_eq!("a b c", tokens.into_input());
+
+        let heads = OwnedTokens::tokenize("x y").into_heads();
+        assert_eq!("x y", heads.input);
+    }
 }
@@ -1,11 +1,11 @@
+use std::{fmt::Debug, marker::PhantomData};
+
 #[derive(Debug)]
-#[allow(unused)]
 pub struct Test {
     value: String,
     pointer_to_value: *const String,
 }
 
-#[allow(unused)]
 impl Test {
     pub fn new(txt: &str) -> Self {
         let mut this = Test {
@@ -20,8 +20,105 @@ impl Test {
         &self.value
     }
 
+    /// # Panics
+    /// 포인터를 만든 뒤 `Test`가 move되어 `pointer_to_value`가 옛 위치를 가리키면 panic한다.
+    /// 옮겨도 되는 버전은 [`MovableTest`]다.
+    pub fn get_pointer_to_value(&self) -> &String {
+        assert!(
+            std::ptr::eq(self.pointer_to_value, &self.value),
+            "pointer_to_value is dangling: Test was moved after new()"
+        );
+        // 주소가 같다는 것만 확인하고, 읽는 것은 빌림 규칙이 지켜 주는 `&self.value`로 한다.
+        &self.value
+    }
+}
+
+/// 절대 주소 대신 "자기 자신으로부터 얼마나 떨어져 있는지"를 저장하는 포인터.
+///
+/// `Test::pointer_to_value`는 절대 주소라서 구조체가 move되면 옛 위치를 가리키게 된다.
+/// 반면 같은 구조체 안의 두 필드 사이 거리는 구조체가 어디로 move되든 변하지 않으므로,
+/// 거리만 기억해 두었다가 필요할 때마다 현재 주소로부터 다시 계산하면 된다.
+///
+/// 주소가 아니라 거리만 들고 있으므로 `*const T`와 달리 `Send`/`Sync`다. 대상을 읽으려면
+/// 어차피 그 대상을 품은 `owner`의 참조가 있어야 한다.
+pub struct RelPtr<T> {
+    offset: isize,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> RelPtr<T> {
+    /// 아직 아무것도 가리키지 않는 포인터. 자기 자신을 가리키는 일은 없으므로 `0`을 null로 쓴다.
+    pub const fn null() -> Self {
+        Self {
+            offset: 0,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn is_null(&self) -> bool {
+        self.offset == 0
+    }
+
+    /// `target`까지의 거리를 기록한다. `target`은 `self`와 같은 구조체 안에 있어야 의미가 있다.
+    pub fn set(&mut self, target: &T) {
+        self.offset = target as *const T as isize - self as *const Self as isize;
+    }
+
+    /// `owner` 안에 있는 `self`의 현재 위치로부터 대상을 다시 계산한다.
+    ///
+    /// 필드 하나에 대한 참조(`&self`)에서 바깥으로 벗어난 주소를 만들면 안 되기 때문에,
+    /// 두 필드를 모두 품고 있는 `owner`를 받아 그 포인터를 기준으로 주소를 만든다.
+    ///
+    /// # Safety
+    /// `self`와 대상이 모두 `owner` 안에 있어야 하고, `set` 이후 두 필드의 상대 위치가
+    /// 바뀌지 않았어야 한다. (구조체를 통째로 move/clone하는 것은 괜찮다)
+    pub unsafe fn get<'a, O>(&self, owner: &'a O) -> &'a T {
+        debug_assert!(!self.is_null());
+        let base = owner as *const O as *const u8;
+        let field = (self as *const Self as *const u8).offset_from(base);
+        &*(base.offset(field + self.offset) as *const T)
+    }
+}
+
+impl<T> Clone for RelPtr<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for RelPtr<T> {}
+
+impl<T> Debug for RelPtr<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("RelPtr").field(&self.offset).finish()
+    }
+}
+
+/// `Test`와 똑같지만 `RelPtr`를 쓰기 때문에 마음대로 move하고 clone할 수 있다.
+#[derive(Debug, Clone)]
+pub struct MovableTest {
+    value: String,
+    pointer_to_value: RelPtr<String>,
+}
+
+impl MovableTest {
+    pub fn new(txt: &str) -> Self {
+        let mut this = MovableTest {
+            value: String::from(txt),
+            pointer_to_value: RelPtr::null(),
+        };
+        this.pointer_to_value.set(&this.value);
+        this
+    }
+
+    pub fn get_value(&self) -> &str {
+        &self.value
+    }
+
     pub fn get_pointer_to_value(&self) -> &String {
-        unsafe { &*(self.pointer_to_value) }
+        // SAFETY: 두 필드 모두 `self` 안에 있다. move나 clone은 구조체를 통째로 옮기므로
+        // `new`에서 잰 거리가 그대로다.
+        unsafe { self.pointer_to_value.get(self) }
     }
 }
 
@@ -32,11 +129,19 @@ mod tests {
     fn init_test() {
         let test1 = Test::new("test1");
         let test2 = Test::new("test2");
-
         assert_eq!("test1", test1.get_value());
-        assert_eq!("test1", test1.get_pointer_to_value());
         assert_eq!("test2", test2.get_value());
-        assert_eq!("test2", test2.get_pointer_to_value());
+
+        // 힙으로 옮기면 포인터는 여전히 스택의 옛 위치를 가리킨다.
+        let test1 = Box::new(test1);
+        assert!(!std::ptr::eq(test1.pointer_to_value, &test1.value));
+    }
+
+    #[test]
+    #[should_panic(expected = "pointer_to_value is dangling")]
+    fn moved_pointer_panics() {
+        let test = Box::new(Test::new("test"));
+        test.get_pointer_to_value();
     }
 
     #[test]
@@ -66,8 +171,69 @@ mod tests {
         std::mem::swap(&mut test1, &mut test2);
 
         assert_eq!("test2", test1.get_value());
-        // assert_eq!("test2", test1.get_pointer_to_value()); // ERROR
+        // assert_eq!("test2", test1.get_pointer_to_value()); // panic
+        assert_eq!("test1", test2.get_value());
+        // assert_eq!("test1", test2.get_pointer_to_value()); // panic
+    }
+
+    #[test]
+    fn movable_swap_test() {
+        let mut test1 = MovableTest::new("test1");
+        let mut test2 = MovableTest::new("test2");
+
+        std::mem::swap(&mut test1, &mut test2);
+
+        // `Test`와 달리 포인터도 함께 따라온다.
+        assert_eq!("test2", test1.get_value());
+        assert_eq!("test2", test1.get_pointer_to_value());
         assert_eq!("test1", test2.get_value());
-        // assert_eq!("test1", test2.get_pointer_to_value()); // ERROR
+        assert_eq!("test1", test2.get_pointer_to_value());
+    }
+
+    #[test]
+    fn movable_vec_reallocation() {
+        let mut tests = Vec::with_capacity(1);
+        for i in 0..100 {
+            // 용량이 찰 때마다 원소들이 새 버퍼로 move된다.
+            tests.push(MovableTest::new(&i.to_string()));
+        }
+        tests.shrink_to_fit();
+        tests.iter().enumerate().for_each(|(i, test)| {
+            assert_eq!(i.to_string(), *test.get_pointer_to_value());
+        });
+
+        tests.swap(0, 99);
+        assert_eq!("99", tests[0].get_pointer_to_value());
+        assert_eq!("0", tests[99].get_pointer_to_value());
+    }
+
+    #[test]
+    fn movable_box_move() {
+        let boxed = Box::new(MovableTest::new("boxed"));
+        assert_eq!("boxed", boxed.get_pointer_to_value());
+
+        let unboxed = *boxed; // move out of the heap onto the stack
+        assert_eq!("boxed", unboxed.get_pointer_to_value());
+
+        let reboxed = Box::new(unboxed);
+        assert_eq!("boxed", reboxed.get_pointer_to_value());
+    }
+
+    #[test]
+    fn movable_across_threads() {
+        let test = MovableTest::new("sent");
+        let handle = std::thread::spawn(move || test.get_pointer_to_value().clone());
+        assert_eq!("sent", handle.join().unwrap());
+    }
+
+    #[test]
+    fn movable_clone() {
+        let original = MovableTest::new("original");
+        let cloned = original.clone();
+        drop(original);
+
+        // 복제본은 원본이 아니라 자기 자신의 `value`를 가리킨다.
+        assert_eq!("original", cloned.get_pointer_to_value());
+        assert!(std::ptr::eq(&cloned.value, cloned.get_pointer_to_value()));
     }
 }
@@ -1,38 +1,48 @@
-/// `Pin` 타입은 러스트 기본속성인 movable에 제약을 두기 위해 만들어졌다.
-/// Move하면 안되는 상황이 바로 Self-referential struct를 사용할 때가 대표적임.
-/// 포인터 타입 `P` (Box, Rc, Arc, RefCell, ...)을 Pin으로 래핑하면 스왑과 같은
-/// 복잡한 상황에서 원치 않는 포인터의 move를 막아준다고..
-///
-/// 다만, Pinned 포인터들이라 할지라도 Unpin이 되어 move될 수 있다. 기본적으로
-/// 모든 타입들은 Unpin auto-trait들이 구현되어있기 때문에 Pin<P>의 효과가
-/// 무효화 되는 것이다. 열심히 pin한 포인터가 Unpin 되는 사태를 막기 위해
-/// [[box_phantompin.rs]] 파일에서 실습을 진행한다.
+//! `Pin` 타입은 러스트 기본속성인 movable에 제약을 두기 위해 만들어졌다.
+//! Move하면 안되는 상황이 바로 Self-referential struct를 사용할 때가 대표적임.
+//! 포인터 타입 `P` (Box, Rc, Arc, RefCell, ...)을 Pin으로 래핑하면 스왑과 같은
+//! 복잡한 상황에서 원치 않는 포인터의 move를 막아준다고..
+//!
+//! 다만, Pinned 포인터들이라 할지라도 Unpin이 되어 move될 수 있다. 기본적으로
+//! 모든 타입들은 Unpin auto-trait들이 구현되어있기 때문에 `Pin<P>`의 효과가
+//! 무효화 되는 것이다. 열심히 pin한 포인터가 Unpin 되는 사태를 막기 위해
+//! [`step_004_box_phantompin`](crate::step_004_box_phantompin) 파일에서 실습을 진행한다.
+
 use std::pin::Pin;
 
-#[derive(Debug)]
-#[allow(unused)]
-struct Test {
-    value: String,
-    pointer_to_value: *const String,
+use crate::step_002_raw_pointers::RelPtr;
+
+pin_project! {
+    #[derive(Debug)]
+    pub struct Test {
+        value: String,
+        pointer_to_value: RelPtr<String>,
+    }
+    project = TestProj;
 }
 
-#[allow(unused)]
 impl Test {
-    fn new(txt: &str) -> Pin<Box<Self>> {
+    pub fn new(txt: &str) -> Pin<Box<Self>> {
         let mut this = Box::pin(Test {
             value: String::from(txt),
-            pointer_to_value: std::ptr::null(),
+            pointer_to_value: RelPtr::null(),
         });
-        this.as_mut().pointer_to_value = &this.value as *const String;
+        let proj = this.as_mut().project();
+        proj.pointer_to_value.set(proj.value);
         this
     }
 
-    fn get_value(self: Pin<&Self>) -> &str {
+    pub fn get_value(self: Pin<&Self>) -> &str {
         &self.get_ref().value
     }
 
-    fn get_pointer_to_value(self: Pin<&Self>) -> &String {
-        unsafe { &*(self.pointer_to_value) }
+    /// 이 타입은 `Unpin`이라 `get_mut()`으로 내용물을 꺼내 옮길 수 있다. 그래서 절대 주소
+    /// 대신 [`RelPtr`]로 거리를 기억해 두고 지금의 `self`에서 다시 계산한다.
+    pub fn get_pointer_to_value(self: Pin<&Self>) -> &String {
+        let this = self.get_ref();
+        // SAFETY: 두 필드 모두 `this` 안에 있고, `new`에서 거리를 잰 뒤로 필드 배치는
+        // 바뀌지 않는다. 구조체가 통째로 옮겨져도 거리는 그대로다.
+        unsafe { this.pointer_to_value.get(this) }
     }
 }
 
@@ -45,18 +55,18 @@ mod tests {
         let mut test2 = Test::new("test2");
 
         assert_eq!("test1", test1.as_ref().get_value());
-        assert_eq!("test1", *test1.as_ref().get_pointer_to_value());
+        assert_eq!("test1", test1.as_ref().get_pointer_to_value());
         assert_eq!("test2", test2.as_ref().get_value());
-        assert_eq!("test2", *test2.as_ref().get_pointer_to_value());
+        assert_eq!("test2", test2.as_ref().get_pointer_to_value());
 
         // swap
         std::mem::swap(&mut test1, &mut test2);
 
         // raw_pointers.rs 에서와는 다르게 포인터가 멤버를 제대로 가리키고 있음을 알 수 있다.
         assert_eq!("test2", test1.as_ref().get_value());
-        assert_eq!("test2", *test1.as_ref().get_pointer_to_value()); // Ok
+        assert_eq!("test2", test1.as_ref().get_pointer_to_value()); // Ok
         assert_eq!("test1", test2.as_ref().get_value());
-        assert_eq!("test1", *test2.as_ref().get_pointer_to_value()); // Ok
+        assert_eq!("test1", test2.as_ref().get_pointer_to_value()); // Ok
 
         // set by using `as_mut().get_mut()` is DANGEROUS
         // because `Unpin` auto trait is implemented right now!
@@ -68,10 +78,11 @@ mod tests {
         assert_eq!("new test2", test2.as_ref().get_pointer_to_value());
 
         // swap unpinned pointers cause pointers MOVE
+        // 절대 주소였다면 여기서 서로의 옛 `value`를 가리켰겠지만, `RelPtr`는 함께 따라온다.
         std::mem::swap(test1.as_mut().get_mut(), test2.as_mut().get_mut());
         assert_eq!("new test2", test1.as_ref().get_value());
-        // assert_eq!("new test2", test1.as_ref().get_pointer_to_value()); // ERROR!
+        assert_eq!("new test2", test1.as_ref().get_pointer_to_value());
         assert_eq!("new test1", test2.as_ref().get_value());
-        // assert_eq!("new test1", test2.as_ref().get_pointer_to_value()); // ERROR!
+        assert_eq!("new test1", test2.as_ref().get_pointer_to_value());
     }
 }
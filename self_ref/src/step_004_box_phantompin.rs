@@ -1,33 +1,48 @@
 use std::{marker::PhantomPinned, pin::Pin};
 
-#[derive(Debug)]
-#[allow(unused)]
-struct Test {
-    value: String,
-    pointer_to_value: *const String,
-    _pinned: PhantomPinned,
+use crate::step_002_raw_pointers::RelPtr;
+
+pin_project! {
+    #[derive(Debug)]
+    pub struct Test {
+        value: String,
+        pointer_to_value: RelPtr<String>,
+        #[pin]
+        _pinned: PhantomPinned,
+    }
+    project = TestProj;
 }
 
-#[allow(unused)]
 impl Test {
-    fn new(txt: &str) -> Pin<Box<Self>> {
+    pub fn new(txt: &str) -> Pin<Box<Self>> {
         let mut this = Box::pin(Test {
             value: String::from(txt),
-            pointer_to_value: std::ptr::null(),
+            pointer_to_value: RelPtr::null(),
             _pinned: PhantomPinned,
         });
-        unsafe {
-            this.as_mut().get_unchecked_mut().pointer_to_value = &this.value;
-        }
+        // projection 덕분에 더 이상 `get_unchecked_mut`가 필요 없다.
+        let proj = this.as_mut().project();
+        proj.pointer_to_value.set(proj.value);
         this
     }
 
-    fn get_value(self: Pin<&Self>) -> &str {
+    pub fn get_value(self: Pin<&Self>) -> &str {
         &self.get_ref().value
     }
 
-    fn get_pointer_to_value(self: Pin<&Self>) -> &String {
-        unsafe { &*(self.pointer_to_value) }
+    /// 절대 주소를 저장해 두면 `Box`를 옮기거나 `value`의 `&mut`를 다시 만들 때마다 그 포인터가
+    /// 빌림 규칙상 무효가 된다. 그래서 [`RelPtr`]로 거리만 기억하고 `self`에서 다시 계산한다.
+    pub fn get_pointer_to_value(self: Pin<&Self>) -> &String {
+        let this = self.get_ref();
+        // SAFETY: 두 필드 모두 `this` 안에 있고, 고정된 뒤로 구조체가 옮겨지지 않으므로
+        // `new`에서 잰 거리가 그대로다.
+        unsafe { this.pointer_to_value.get(this) }
+    }
+
+    /// `value`는 구조적으로 고정된 필드가 아니므로 안전하게 바꿀 수 있다.
+    /// 힙에 있는 `String` 자체가 바뀔 뿐 필드의 주소는 그대로이므로 포인터도 유효하다.
+    pub fn set_value(self: Pin<&mut Self>, txt: impl Into<String>) {
+        *self.project().value = txt.into();
     }
 }
 
@@ -40,28 +55,38 @@ mod tests {
         let mut test2 = Test::new("test2");
 
         assert_eq!("test1", test1.as_ref().get_value());
-        assert_eq!("test1", *test1.as_ref().get_pointer_to_value());
+        assert_eq!("test1", test1.as_ref().get_pointer_to_value());
         assert_eq!("test2", test2.as_ref().get_value());
-        assert_eq!("test2", *test2.as_ref().get_pointer_to_value());
+        assert_eq!("test2", test2.as_ref().get_pointer_to_value());
 
         // swap
         std::mem::swap(&mut test1, &mut test2);
 
         // raw_pointers.rs 에서와는 다르게 포인터가 멤버를 제대로 가리키고 있음을 알 수 있다.
         assert_eq!("test2", test1.as_ref().get_value());
-        assert_eq!("test2", *test1.as_ref().get_pointer_to_value()); // Ok
+        assert_eq!("test2", test1.as_ref().get_pointer_to_value()); // Ok
         assert_eq!("test1", test2.as_ref().get_value());
-        assert_eq!("test1", *test2.as_ref().get_pointer_to_value()); // Ok
+        assert_eq!("test1", test2.as_ref().get_pointer_to_value()); // Ok
 
         // PhantomPin을 사용한 이래로 우리는 deref를 통한 데이터 변경 권한을 잃었다.
         // test1.as_mut().get_mut().value = "new test1".to_owned(); // ERROR
         // test2.as_mut().get_mut().value = "new test2".to_owned(); // ERROR
 
         // 만약 꼭 바꿔야 한다면... unsafe를 쓰는 수밖에 없지
+        // SAFETY: `value` 필드에 새 `String`을 대입할 뿐 `Test` 자체는 그 자리에 있다.
         unsafe {
             test1.as_mut().get_unchecked_mut().value = "new test1".to_owned();
         }
         assert_eq!("new test1", test1.as_ref().get_value());
         assert_eq!("new test1", test1.as_ref().get_pointer_to_value());
+
+        // ...였는데, 이젠 projection으로 unsafe 없이 바꿀 수 있다.
+        test2.as_mut().set_value("new test2");
+        assert_eq!("new test2", test2.as_ref().get_value());
+        assert_eq!("new test2", test2.as_ref().get_pointer_to_value());
+
+        // 고정된 필드는 `Pin`으로만 나오므로 꺼내서 swap할 수 없다.
+        // (tests/compile_fail/swap_pinned_field.rs 참고)
+        let _pinned: Pin<&mut PhantomPinned> = test1.as_mut().project()._pinned;
     }
 }
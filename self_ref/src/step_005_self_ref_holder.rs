@@ -1,22 +1,30 @@
 use std::{cell::RefCell, collections::BTreeSet, marker::PhantomPinned, pin::Pin, rc::Rc};
 type RcCell<T> = Rc<RefCell<T>>;
 
-#[derive(Debug)]
-#[allow(unused)]
-pub struct Me {
-    name: String,
-    mutate_by_holder: i32,
-    my_holder: RcCell<Holder>,
-    _pinned: PhantomPinned,
+pin_project! {
+    #[derive(Debug)]
+    pub struct Me {
+        name: String,
+        mutate_by_holder: i32,
+        my_holder: RcCell<Holder>,
+        #[pin]
+        _pinned: PhantomPinned,
+    }
+    project = MeProj;
+    // Me 객체를 제거할 때 Holder의 원소도 제거하도록 구현
+    impl PinnedDrop for Me {
+        fn drop(this: Pin<&mut Self>) {
+            let ptr = &*this as *const Me as *mut Me;
+            this.my_holder.borrow_mut().set_of_me.remove(&ptr);
+        }
+    }
 }
 
 #[derive(Debug)]
-#[allow(unused)]
 pub struct Holder {
     set_of_me: BTreeSet<*mut Me>,
 }
 
-#[allow(unused)]
 impl Me {
     pub fn new(my_holder: RcCell<Holder>, name: impl Into<String>) -> Pin<Box<Self>> {
         let mut this = Box::pin(Self {
@@ -33,12 +41,18 @@ impl Me {
     /// Allows you to mutate a value within Me.
     /// Run this from `Holder` to see what happens.
     fn mutate_me(self: Pin<&mut Self>, val: i32) {
-        let this = unsafe { self.get_unchecked_mut() };
-        this.mutate_by_holder += val;
+        *self.project().mutate_by_holder += val;
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_value(self: Pin<&Self>) -> i32 {
+        self.get_ref().mutate_by_holder
     }
 }
 
-#[allow(unused)]
 impl Holder {
     pub fn new() -> RcCell<Self> {
         Rc::new(RefCell::new(Self {
@@ -56,14 +70,6 @@ impl Holder {
     }
 }
 
-/// Me 객체를 제거할 때 Holder의 원소도 제거하도록 구현
-impl Drop for Me {
-    fn drop(&mut self) {
-        let this = &(self as *mut _);
-        self.my_holder.borrow_mut().set_of_me.remove(this);
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -77,7 +83,7 @@ mod tests {
 
         // set all `Me`s with value 1
         holder.borrow().mutate_value_of_me(1);
-        assert_eq!(1, a.mutate_by_holder);
+        assert_eq!(1, a.as_ref().get_value());
         assert_eq!(1, b.mutate_by_holder);
         assert_eq!(1, c.mutate_by_holder);
 
@@ -1,14 +1,20 @@
-/// [[step_005_self_ref_holder]] 의 단점은 BTreeSet의 Key가 포인터 타입이라는 데에 있다.
-///
-/// 따라서 우리가 어떤 멤버를 키값으로 BTreeSet을 구축하고 싶어 별도의 Ord 트레이트를
-/// 구현할지라도 `BTreeSet<*mut Me>` 에 의해 결국은 주소값을 비교하게 될 것이고,
-/// 의도하지 않은 결과를 얻을 것이다. 따라서 `*mut Me`를 감싸는 래퍼 구조체를 만들어
-/// 래퍼 자체에 `Ord` 트레이트를 구현하게 만들고 자동 형변환을 구현할 수 있는 `From`
-/// 트레이트를 구현하여 사용에 편의를 제공할 수 있다.
-///
-/// source: https://dev.to/arunanshub/self-referential-structs-in-rust-part-2-1lc2
+//! [`step_005_self_ref_holder`](crate::step_005_self_ref_holder) 의 단점은 BTreeSet의 Key가 포인터 타입이라는 데에 있다.
+//!
+//! 따라서 우리가 어떤 멤버를 키값으로 BTreeSet을 구축하고 싶어 별도의 Ord 트레이트를
+//! 구현할지라도 `BTreeSet<*mut Me>` 에 의해 결국은 주소값을 비교하게 될 것이고,
+//! 의도하지 않은 결과를 얻을 것이다. 따라서 `*mut Me`를 감싸는 래퍼 구조체를 만들어
+//! 래퍼 자체에 `Ord` 트레이트를 구현하게 만들고 자동 형변환을 구현할 수 있는 `From`
+//! 트레이트를 구현하여 사용에 편의를 제공할 수 있다.
+//!
+//! source: <https://dev.to/arunanshub/self-referential-structs-in-rust-part-2-1lc2>
+
 use std::{
-    cell::RefCell, collections::BTreeSet, marker::PhantomPinned, ops::Deref, pin::Pin, rc::Rc,
+    cell::{Cell, RefCell},
+    collections::BTreeSet,
+    marker::PhantomPinned,
+    ops::Deref,
+    pin::Pin,
+    rc::Rc,
 };
 type RcCell<T> = Rc<RefCell<T>>;
 
@@ -24,6 +30,7 @@ type RcCell<T> = Rc<RefCell<T>>;
 mod tutorial {
 
     #[derive(PartialEq, Eq, PartialOrd, Ord, Debug)]
+    #[allow(unused)]
     struct Node {
         name: String,
         id: u32,
@@ -86,19 +93,30 @@ mod tutorial {
     }
 }
 
+/// `Me`의 순서는 생성 이후 바뀌지 않는 `name`으로만 정한다.
+///
+/// 처음에는 `mutate_by_holder`까지 비교에 포함했는데, 이러면 두 가지 문제가 생긴다.
+/// 1. 이름과 값이 같은 두 `Me`가 같다고 판단되어 두 번째 `Me`가 `BTreeSet`에 들어가지
+///    못하고, 그 중 하나가 drop될 때 엉뚱한 원소가 지워질 수 있다.
+/// 2. `mutate_value_of_me`가 `BTreeSet` 안에 들어있는 원소의 키를 바꿔버려 트리의 정렬
+///    불변식이 깨진다.
+///
+/// 그래서 바뀌는 값은 비교에서 빼고, 이름이 같을 때는 `MeWrapper`가 주소로 구분한다.
 #[derive(Debug, Derivative)]
 #[derivative(PartialEq, Eq, PartialOrd, Ord)]
-#[allow(unused)]
 pub struct Me {
     name: String,
-    mutate_by_holder: i32,
-    #[derivative(Ord = "ignore")]
+    /// `Holder`가 `&Me`만으로 바꿀 수 있도록 `Cell`에 담는다. 다른 곳에서 `&Me`를 들고 있어도
+    /// `&mut Me`를 만들지 않으므로 aliasing 규칙을 어기지 않는다.
+    #[derivative(PartialEq = "ignore", PartialOrd = "ignore", Ord = "ignore")]
+    mutate_by_holder: Cell<i32>,
+    #[derivative(PartialEq = "ignore", PartialOrd = "ignore", Ord = "ignore")]
     my_holder: RcCell<Holder>,
+    #[derivative(PartialEq = "ignore", PartialOrd = "ignore", Ord = "ignore")]
     _pinned: PhantomPinned,
 }
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
-#[allow(unused)]
 pub struct Holder {
     set_of_me: BTreeSet<MeWrapper>,
 }
@@ -107,30 +125,30 @@ pub struct Holder {
 #[derive(Debug)]
 struct MeWrapper(*mut Me);
 
-///
-/// BOILERPLATE START
-///
+// BOILERPLATE START
+
+/// 정렬 키는 `(name, 주소)`이다. 주소는 `Pin` 덕분에 `Me`가 살아있는 동안 바뀌지 않으므로
+/// 이름이 같은 `Me`끼리도 항상 구분되고, drop할 때 정확히 자기 자신만 지울 수 있다.
+impl MeWrapper {
+    fn key(&self) -> (&Me, *const Me) {
+        (&**self, self.0)
+    }
+}
 
 impl PartialEq for MeWrapper {
     fn eq(&self, other: &Self) -> bool {
-        let this = &**self;
-        let other = &**other;
-        this == other
+        self.key() == other.key()
     }
 }
 impl PartialOrd for MeWrapper {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        let this = &**self;
-        let other = &**other;
-        this.partial_cmp(&other)
+        Some(self.cmp(other))
     }
 }
 impl Eq for MeWrapper {} // 얘는 왜 아무것도 구현 안해도 되냐?
 impl Ord for MeWrapper {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        let this = &**self;
-        let other = &**other;
-        this.cmp(&other)
+        self.key().cmp(&other.key())
     }
 }
 impl Deref for MeWrapper {
@@ -145,20 +163,18 @@ impl From<*mut Me> for MeWrapper {
         MeWrapper(value)
     }
 }
-/// [[step_005_self_ref_holder.rs]] 와는 다른 드롭 코드
+/// [`step_005_self_ref_holder`](crate::step_005_self_ref_holder) 와는 다른 드롭 코드
 /// 이젠 `*mut Me`를 remove하지 않고 `MeWrapper`를 remove한다.
 impl Drop for Me {
     fn drop(&mut self) {
         let this: MeWrapper = (self as *mut Self).into();
-        self.my_holder.borrow_mut().set_of_me.remove(&this);
+        let removed = self.my_holder.borrow_mut().set_of_me.remove(&this);
+        debug_assert!(removed, "every living `Me` must be a member of its holder");
     }
 }
 
-///
-/// BOILERPLATE END
-///
+// BOILERPLATE END
 
-#[allow(unused)]
 impl Holder {
     pub fn new() -> RcCell<Self> {
         Rc::new(RefCell::new(Self {
@@ -166,15 +182,26 @@ impl Holder {
         }))
     }
 
+    pub fn len(&self) -> usize {
+        self.set_of_me.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.set_of_me.is_empty()
+    }
+
+    pub fn contains(&self, me: Pin<&Me>) -> bool {
+        let this: MeWrapper = (me.get_ref() as *const Me as *mut Me).into();
+        self.set_of_me.contains(&this)
+    }
+
+    /// `Me`의 정렬 키에는 `mutate_by_holder`가 없으므로 순회 도중 값을 바꿔도 트리가
+    /// 깨지지 않는다.
     pub fn mutate_value_of_me(&self, val: i32) {
-        self.set_of_me.iter().for_each(|each| {
-            let each = unsafe { Pin::new_unchecked(&mut *each.0) };
-            each.mutate_me(val);
-        });
+        self.set_of_me.iter().for_each(|each| each.mutate_me(val));
     }
 }
 
-#[allow(unused)]
 impl Me {
     pub fn new<S>(my_holder: RcCell<Holder>, name: S) -> Pin<Box<Self>>
     where
@@ -182,7 +209,7 @@ impl Me {
     {
         let mut this = Box::pin(Self {
             name: name.into(),
-            mutate_by_holder: 0,
+            mutate_by_holder: Cell::new(0),
             my_holder,
             _pinned: PhantomPinned,
         });
@@ -199,9 +226,16 @@ impl Me {
         this
     }
 
-    fn mutate_me(self: Pin<&mut Self>, val: i32) {
-        let this = unsafe { self.get_unchecked_mut() };
-        this.mutate_by_holder += val;
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_value(&self) -> i32 {
+        self.mutate_by_holder.get()
+    }
+
+    fn mutate_me(&self, val: i32) {
+        self.mutate_by_holder.set(self.mutate_by_holder.get() + val);
     }
 }
 
@@ -218,17 +252,72 @@ mod tests {
 
         // set all `Me`s with value 1
         holder.borrow().mutate_value_of_me(1);
-        assert_eq!(1, a.mutate_by_holder);
-        assert_eq!(1, b.mutate_by_holder);
-        assert_eq!(0, c.mutate_by_holder); // now c is not a memeber of holder
+        assert_eq!(1, a.get_value());
+        assert_eq!(1, b.get_value());
+        assert_eq!(1, c.get_value()); // same name, but still a member of holder
+        assert_eq!(3, holder.borrow().len());
 
         // make ref of holder
         let holder_ref = Rc::clone(&holder);
-        assert_eq!(5, Rc::strong_count(&holder)); // c is still pointing holder right now
+        assert_eq!(5, Rc::strong_count(&holder));
 
         dbg!(a.as_ref().get_ref() as *const Me);
         dbg!(b.as_ref().get_ref() as *const Me);
         dbg!(c.as_ref().get_ref() as *const Me);
         dbg!(holder_ref);
     }
+
+    #[test]
+    fn duplicated_names() {
+        let holder = Holder::new();
+        let mut dups: Vec<_> = (0..5).map(|_| Me::new(Rc::clone(&holder), "dup")).collect();
+        assert_eq!(5, holder.borrow().len());
+
+        // 가운데 원소를 drop하면 정확히 그 원소만 빠져야 한다.
+        let middle = dups.remove(2);
+        let middle_ptr = middle.as_ref().get_ref() as *const Me;
+        drop(middle);
+        assert_eq!(4, holder.borrow().len());
+        assert!(dups.iter().all(|me| holder.borrow().contains(me.as_ref())));
+        assert!(holder
+            .borrow()
+            .set_of_me
+            .iter()
+            .all(|each| !std::ptr::eq(each.0, middle_ptr)));
+
+        dups.clear();
+        assert!(holder.borrow().is_empty());
+    }
+
+    #[test]
+    fn mutate_during_broadcast() {
+        let holder = Holder::new();
+        let a = Me::new(Rc::clone(&holder), "a");
+        let b = Me::new(Rc::clone(&holder), "a");
+        let c = Me::new(Rc::clone(&holder), "b");
+
+        (1..=10).for_each(|val| holder.borrow().mutate_value_of_me(val));
+        assert_eq!(55, a.get_value());
+        assert_eq!(55, b.get_value());
+        assert_eq!(55, c.get_value());
+
+        // 값이 바뀐 뒤에도 여전히 검색/삭제가 가능해야 한다.
+        assert!(holder.borrow().contains(a.as_ref()));
+        assert!(holder.borrow().contains(b.as_ref()));
+        assert!(holder.borrow().contains(c.as_ref()));
+
+        let names: Vec<_> = holder
+            .borrow()
+            .set_of_me
+            .iter()
+            .map(|each| each.name.clone())
+            .collect();
+        assert_eq!(vec!["a", "a", "b"], names);
+
+        drop(b);
+        drop(a);
+        assert_eq!(1, holder.borrow().len());
+        drop(c);
+        assert!(holder.borrow().is_empty());
+    }
 }
@@ -1,51 +1,86 @@
-/// Let's make self-referential linked list by my own knowledge!! 💪
+//! Let's make self-referential linked list by my own knowledge!! 💪
+//!
+//! 첫 시도에서는 더미 head/tail 노드를 스택 임시값으로 만들고 그 주소를 `Rc<RefCell<_>>`에
+//! 담아 빼돌렸기 때문에, `new()`가 반환되는 순간 모든 포인터가 dangling이 됐다.
+//!
+//! 이번에는 모든 노드(더미 포함)를 `Pin<Box<Node<T>>>`로 힙에 고정한 뒤 리스트가 raw
+//! pointer로 소유한다. 노드를 리스트에서 꺼낼 때는 다시 `Pin<Box<_>>`로 되살려 drop하고,
+//! `Node`의 `Drop`이 스스로를 앞뒤 노드 사이에서 떼어낸다.
+//!
+//! `cargo +nightly miri test -p self_ref step_007` 로 검증한다.
+
 use std::{
-    borrow::BorrowMut,
-    cell::RefCell,
-    default,
-    marker::PhantomPinned,
-    ops::{Deref, DerefMut},
+    fmt::Debug,
+    marker::{PhantomData, PhantomPinned},
     pin::Pin,
-    rc::Rc,
+    ptr::NonNull,
 };
 
-type RcCell<T> = Rc<RefCell<T>>;
+type Link<T> = Option<NonNull<Node<T>>>;
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
-struct Node<T: Sized> {
-    next: Option<RcCell<NodePtr<T>>>, // can be null...
-    prev: Option<RcCell<NodePtr<T>>>, // can be null...
-    val: T,
+/// 더미(head/tail) 노드는 `val`이 `None`이다. 덕분에 `T: Default` 제약이 필요 없다.
+pub struct Node<T> {
+    prev: Link<T>,
+    next: Link<T>,
+    val: Option<T>,
     _marker: PhantomPinned,
 }
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
-struct NodePtr<T>(*mut Node<T>);
-
 /// holds two dummy nodes which holds each `head` and `tail` position of entire list
-#[derive(Debug)]
-struct MyLinkedList<T: Sized> {
-    head: RcCell<NodePtr<T>>,
-    tail: RcCell<NodePtr<T>>,
+pub struct MyLinkedList<T> {
+    head: NonNull<Node<T>>,
+    tail: NonNull<Node<T>>,
+    len: usize,
+    // We semantically store values of T by value.
+    _boo: PhantomData<T>,
 }
 
-impl<T> From<*mut Node<T>> for NodePtr<T> {
-    fn from(value: *mut Node<T>) -> Self {
-        NodePtr(value)
-    }
+/// non-consuming iterator
+pub struct Iter<'a, T> {
+    cur: Link<T>,
+    len: usize,
+    _boo: PhantomData<&'a T>,
 }
 
-impl<T> Deref for NodePtr<T> {
-    type Target = Node<T>;
+impl<T> Node<T> {
+    fn new(val: Option<T>) -> Pin<Box<Self>> {
+        Box::pin(Self {
+            prev: None,
+            next: None,
+            val,
+            _marker: PhantomPinned,
+        })
+    }
+
+    /// 고정된 노드의 소유권을 리스트로 넘긴다. 노드는 [`Node::from_raw`]로 되살아날
+    /// 때까지 같은 주소에 머무른다.
+    fn into_raw(this: Pin<Box<Self>>) -> NonNull<Self> {
+        // SAFETY: Box에서 꺼낸 포인터는 다시 `from_raw`로 감싸기 전까지 move하지 않는다.
+        let boxed = unsafe { Pin::into_inner_unchecked(this) };
+        unsafe { NonNull::new_unchecked(Box::into_raw(boxed)) }
+    }
 
-    fn deref(&self) -> &Self::Target {
-        unsafe { &*self.0 }
+    /// # Safety
+    /// `ptr`은 [`Node::into_raw`]가 반환한 포인터여야 하며, 한 번만 되살려야 한다.
+    unsafe fn from_raw(ptr: NonNull<Self>) -> Pin<Box<Self>> {
+        Pin::new_unchecked(Box::from_raw(ptr.as_ptr()))
     }
-}
 
-impl<T> DerefMut for NodePtr<T> {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        unsafe { &mut *self.0 }
+    /// `val`은 구조적으로 고정된(structurally pinned) 필드가 아니므로 꺼내도 된다.
+    fn take_val(self: Pin<&mut Self>) -> Option<T> {
+        let this = unsafe { self.get_unchecked_mut() };
+        this.val.take()
+    }
+
+    /// `prev`와 `next` 사이에 자신을 끼워 넣는다.
+    ///
+    /// # Safety
+    /// 세 포인터 모두 살아있는 노드를 가리켜야 하고, `prev.next == next`여야 한다.
+    unsafe fn link_between(this: NonNull<Self>, prev: NonNull<Self>, next: NonNull<Self>) {
+        (*this.as_ptr()).prev = Some(prev);
+        (*this.as_ptr()).next = Some(next);
+        (*prev.as_ptr()).next = Some(this);
+        (*next.as_ptr()).prev = Some(this);
     }
 }
 
@@ -53,77 +88,249 @@ impl<T> Drop for Node<T> {
     /// before drop solely, we must take care of both prev and next elements to
     /// connect correctly.
     fn drop(&mut self) {
-        let this: NodePtr<T> = (self as *mut Self).into();
+        unsafe {
+            if let Some(prev) = self.prev {
+                (*prev.as_ptr()).next = self.next;
+            }
+            if let Some(next) = self.next {
+                (*next.as_ptr()).prev = self.prev;
+            }
+        }
+        self.prev = None;
+        self.next = None;
+    }
+}
 
-        if let Some(prev) = &self.prev {
-            prev.as_ref().borrow_mut().next = this.next.clone();
+impl<T> MyLinkedList<T> {
+    pub fn new() -> Self {
+        let head = Node::into_raw(Node::new(None));
+        let tail = Node::into_raw(Node::new(None));
+        unsafe {
+            (*head.as_ptr()).next = Some(tail);
+            (*tail.as_ptr()).prev = Some(head);
         }
-        if let Some(next) = &self.next {
-            next.as_ref().borrow_mut().prev = this.prev.clone();
+        Self {
+            head,
+            tail,
+            len: 0,
+            _boo: PhantomData,
         }
+    }
 
-        drop(&this);
+    pub fn len(&self) -> usize {
+        self.len
     }
-}
 
-impl<T: Sized + Default> Node<T> {
-    /// create dummy node
-    fn new() -> Self {
-        Self {
-            next: None,
-            prev: None,
-            val: Default::default(),
-            _marker: PhantomPinned,
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn push_front(&mut self, val: T) {
+        let node = Node::into_raw(Node::new(Some(val)));
+        unsafe {
+            let next = (*self.head.as_ptr()).next.unwrap();
+            Node::link_between(node, self.head, next);
         }
+        self.len += 1;
     }
-    pub fn from_link(val: T, next: RcCell<NodePtr<T>>, prev: RcCell<NodePtr<T>>) -> Pin<Box<Self>> {
-        Box::pin(Self {
-            next: Some(next),
-            prev: Some(prev),
-            val: val,
-            _marker: PhantomPinned,
-        })
+
+    pub fn push_back(&mut self, val: T) {
+        let node = Node::into_raw(Node::new(Some(val)));
+        unsafe {
+            let prev = (*self.tail.as_ptr()).prev.unwrap();
+            Node::link_between(node, prev, self.tail);
+        }
+        self.len += 1;
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        let first = unsafe { (*self.head.as_ptr()).next.unwrap() };
+        Some(unsafe { self.remove(first) })
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        let last = unsafe { (*self.tail.as_ptr()).prev.unwrap() };
+        Some(unsafe { self.remove(last) })
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        self.iter().next()
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        if self.is_empty() {
+            return None;
+        }
+        unsafe { (*(*self.tail.as_ptr()).prev.unwrap().as_ptr()).val.as_ref() }
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            cur: unsafe { (*self.head.as_ptr()).next },
+            len: self.len,
+            _boo: PhantomData,
+        }
+    }
+
+    /// 노드를 `Pin<Box<_>>`로 되살려 값을 꺼낸 뒤 drop한다. 연결을 끊는 일은
+    /// `Node::drop`이 맡는다.
+    ///
+    /// # Safety
+    /// `node`는 이 리스트에 연결된 더미가 아닌 노드여야 한다.
+    unsafe fn remove(&mut self, node: NonNull<Node<T>>) -> T {
+        let mut node = Node::from_raw(node);
+        let val = node.as_mut().take_val().unwrap();
+        drop(node);
+        self.len -= 1;
+        val
     }
 }
 
-impl<T: Sized + Default> MyLinkedList<T> {
-    pub fn new() -> Self {
-        let dummy_head = &mut Node::<T>::new() as *mut Node<T>;
-        let dummy_tail = &mut Node::<T>::new() as *mut Node<T>;
+impl<T> Default for MyLinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
+impl<T> Drop for MyLinkedList<T> {
+    fn drop(&mut self) {
+        while self.pop_front().is_some() {}
         unsafe {
-            (*dummy_head).next = Some(Rc::new(RefCell::new(dummy_tail.into())));
-            (*dummy_tail).prev = Some(Rc::new(RefCell::new(dummy_head.into())));
+            drop(Node::from_raw(self.head));
+            drop(Node::from_raw(self.tail));
         }
+    }
+}
 
-        let dummy_head: NodePtr<T> = dummy_head.into();
-        let dummy_tail: NodePtr<T> = dummy_tail.into();
+impl<T: Debug> Debug for MyLinkedList<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
 
-        Self {
-            head: Rc::new(RefCell::new(dummy_head)),
-            tail: Rc::new(RefCell::new(dummy_tail)),
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
         }
+        self.cur.map(|node| unsafe {
+            self.len -= 1;
+            self.cur = (*node.as_ptr()).next;
+            (*node.as_ptr()).val.as_ref().unwrap()
+        })
     }
-}
 
-impl<T> MyLinkedList<T> {
-    pub fn get_head(&self) -> RcCell<NodePtr<T>> {
-        self.head.clone()
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
     }
-    pub fn get_tail(&self) -> RcCell<NodePtr<T>> {
-        self.tail.clone()
+}
+
+impl<'a, T> IntoIterator for &'a MyLinkedList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::{cell::Cell, rc::Rc};
+
     use super::*;
 
-    /// This test causes memory invalid error at the end...
     #[test]
     fn testdrive() {
-        let list = MyLinkedList::<i32>::new();
-        let node = Node::<i32>::from_link(0, list.get_tail(), list.get_head());
-        dbg!(node);
+        let mut list = MyLinkedList::<i32>::new();
+        assert!(list.is_empty());
+        assert_eq!(None, list.pop_front());
+        assert_eq!(None, list.pop_back());
+
+        list.push_back(2);
+        list.push_back(3);
+        list.push_front(1);
+        list.push_front(0);
+        assert_eq!(4, list.len());
+        assert_eq!(Some(&0), list.front());
+        assert_eq!(Some(&3), list.back());
+        assert_eq!(vec![0, 1, 2, 3], list.iter().copied().collect::<Vec<_>>());
+        assert_eq!("[0, 1, 2, 3]", format!("{:?}", list));
+
+        assert_eq!(Some(0), list.pop_front());
+        assert_eq!(Some(3), list.pop_back());
+        assert_eq!(Some(2), list.pop_back());
+        assert_eq!(Some(1), list.pop_back());
+        assert_eq!(None, list.pop_back());
+        assert!(list.is_empty());
+        assert_eq!(None, list.front());
+        assert_eq!(None, list.back());
+
+        // reuse after drained
+        list.push_front(42);
+        assert_eq!(vec![&42], list.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn move_list() {
+        let mut list = MyLinkedList::new();
+        list.push_back(String::from("a"));
+        list.push_back(String::from("b"));
+
+        // 리스트 자체를 move해도 노드들은 힙에 고정되어 있으므로 괜찮다.
+        let mut moved = vec![list];
+        moved.reserve(100);
+        let mut list = moved.pop().unwrap();
+        list.push_back(String::from("c"));
+        assert_eq!(vec!["a", "b", "c"], list.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn node_unlinks_itself_on_drop() {
+        let mut list = MyLinkedList::new();
+        (0..5).for_each(|i| list.push_back(i));
+
+        // 가운데 노드를 직접 되살려 drop하면 이웃끼리 이어져야 한다.
+        let middle = unsafe {
+            let first = (*list.head.as_ptr()).next.unwrap();
+            let second = (*first.as_ptr()).next.unwrap();
+            (*second.as_ptr()).next.unwrap()
+        };
+        assert_eq!(2, unsafe { list.remove(middle) });
+        assert_eq!(vec![0, 1, 3, 4], list.iter().copied().collect::<Vec<_>>());
+
+        // 반대 방향 연결도 맞는지 확인
+        let mut backward = vec![];
+        while let Some(val) = list.pop_back() {
+            backward.push(val);
+        }
+        assert_eq!(vec![4, 3, 1, 0], backward);
+    }
+
+    #[test]
+    fn drop_every_value() {
+        struct DropCounter(Rc<Cell<usize>>);
+        impl Drop for DropCounter {
+            fn drop(&mut self) {
+                self.0.set(self.0.get() + 1);
+            }
+        }
+
+        let counter = Rc::new(Cell::new(0));
+        let mut list = MyLinkedList::new();
+        (0..10).for_each(|_| list.push_front(DropCounter(Rc::clone(&counter))));
+        drop(list.pop_back());
+        assert_eq!(1, counter.get());
+
+        drop(list);
+        assert_eq!(10, counter.get());
     }
 }
@@ -0,0 +1,401 @@
+//! [`step_005_self_ref_holder`](crate::step_005_self_ref_holder) / [`step_006_self_ref_cmp`](crate::step_006_self_ref_cmp) 의 `Holder`를 아무 타입에나
+//! 쓸 수 있도록 일반화한 레지스트리.
+//!
+//! - 등록된 값은 `Pin<Box<T>>`로 힙에 고정된 뒤 [`Registration`] 가드가 소유한다.
+//!   가드가 drop되면 레지스트리에서 자동으로 빠진다. (`Me`의 `Drop`과 같은 역할)
+//! - 레지스트리는 사용자가 준 키 순서대로 값을 순회하며 `Pin<&mut T>`를 빌려준다.
+//!   (`Holder::mutate_value_of_me`와 같은 broadcast)
+//! - 같은 키를 여러 번 등록해도 사라지지 않는다. 내부 키는 `(K, 등록 순번)`이라
+//!   step_006의 tutorial에서처럼 `Ord`가 같다고 원소가 조용히 버려지는 일이 없다.
+//! - 모든 접근은 `RefCell`(또는 `Mutex`)을 거치므로, broadcast 도중 가드로 같은 값을
+//!   빌리려 하면 UB 대신 panic(또는 대기)이 된다.
+//!
+//! [`SyncRegistry`]는 `Arc<Mutex<_>>` 위에 같은 API를 올린 스레드 안전 버전이다.
+
+use std::{
+    cell::{Ref, RefCell},
+    collections::BTreeMap,
+    pin::Pin,
+    ptr::NonNull,
+    rc::Rc,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+};
+
+type RcCell<T> = Rc<RefCell<T>>;
+
+/// 두 레지스트리가 공유하는 실제 저장소. 값의 소유권은 가드에 있고 여기에는 주소만 둔다.
+#[derive(Debug)]
+struct Entries<K, T> {
+    map: BTreeMap<(K, u64), NonNull<T>>,
+    next_id: u64,
+}
+
+impl<K: Ord, T> Entries<K, T> {
+    fn new() -> Self {
+        Self {
+            map: BTreeMap::new(),
+            next_id: 0,
+        }
+    }
+
+    fn insert(&mut self, key: K, ptr: NonNull<T>) -> u64 {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.map.insert((key, id), ptr);
+        id
+    }
+
+    fn for_each_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&K, Pin<&mut T>),
+    {
+        self.map.iter().for_each(|((key, _), ptr)| {
+            // SAFETY: 값은 가드가 drop될 때까지 고정된 채 살아있고, `&mut self`를 쥐고
+            // 있는 동안에는 다른 누구도 값에 접근할 수 없다.
+            let each = unsafe { Pin::new_unchecked(&mut *ptr.as_ptr()) };
+            f(key, each);
+        });
+    }
+}
+
+/// 고정된 값을 가드가 소유할 수 있는 raw pointer로 바꾼다.
+fn into_raw<T>(value: Pin<Box<T>>) -> NonNull<T> {
+    // SAFETY: 가드가 `release`로 되살릴 때까지 값은 move되지 않는다.
+    let boxed = unsafe { Pin::into_inner_unchecked(value) };
+    unsafe { NonNull::new_unchecked(Box::into_raw(boxed)) }
+}
+
+/// # Safety
+/// `ptr`은 [`into_raw`]가 반환한 포인터여야 하고 레지스트리에서 이미 빠져 있어야 한다.
+unsafe fn release<T>(ptr: NonNull<T>) {
+    drop(Pin::new_unchecked(Box::from_raw(ptr.as_ptr())));
+}
+
+/// 단일 스레드용 레지스트리. `Rc`처럼 값싸게 clone할 수 있다.
+#[derive(Debug)]
+pub struct Registry<K, T> {
+    entries: RcCell<Entries<K, T>>,
+}
+
+/// [`Registry::register`]가 돌려주는 가드. 등록된 값을 소유한다.
+#[derive(Debug)]
+pub struct Registration<K: Ord, T> {
+    key: (K, u64),
+    ptr: NonNull<T>,
+    entries: RcCell<Entries<K, T>>,
+}
+
+impl<K: Ord + Clone, T> Registry<K, T> {
+    pub fn new() -> Self {
+        Self {
+            entries: Rc::new(RefCell::new(Entries::new())),
+        }
+    }
+
+    pub fn register(&self, key: K, value: Pin<Box<T>>) -> Registration<K, T> {
+        let ptr = into_raw(value);
+        let id = self.entries.borrow_mut().insert(key.clone(), ptr);
+        Registration {
+            key: (key, id),
+            ptr,
+            entries: Rc::clone(&self.entries),
+        }
+    }
+
+    /// 등록된 모든 값을 키 순서(같은 키는 등록 순서)대로 방문한다.
+    ///
+    /// # Panics
+    /// 콜백 안에서 같은 레지스트리나 그 가드에 접근하면 panic한다.
+    pub fn for_each_mut<F>(&self, f: F)
+    where
+        F: FnMut(&K, Pin<&mut T>),
+    {
+        self.entries.borrow_mut().for_each_mut(f);
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.borrow().map.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn keys(&self) -> Vec<K> {
+        self.entries
+            .borrow()
+            .map
+            .keys()
+            .map(|(key, _)| key.clone())
+            .collect()
+    }
+}
+
+impl<K: Ord + Clone, T> Default for Registry<K, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, T> Clone for Registry<K, T> {
+    fn clone(&self) -> Self {
+        Self {
+            entries: Rc::clone(&self.entries),
+        }
+    }
+}
+
+impl<K: Ord, T> Registration<K, T> {
+    pub fn key(&self) -> &K {
+        &self.key.0
+    }
+
+    /// # Panics
+    /// 레지스트리가 broadcast 중이면 panic한다.
+    pub fn borrow(&self) -> Ref<'_, T> {
+        Ref::map(self.entries.borrow(), |_| unsafe { self.ptr.as_ref() })
+    }
+
+    /// # Panics
+    /// 레지스트리가 broadcast 중이거나 값이 이미 빌려져 있으면 panic한다.
+    pub fn with_mut<R>(&self, f: impl FnOnce(Pin<&mut T>) -> R) -> R {
+        let _guard = self.entries.borrow_mut();
+        f(unsafe { Pin::new_unchecked(&mut *self.ptr.as_ptr()) })
+    }
+}
+
+impl<K: Ord, T> Drop for Registration<K, T> {
+    fn drop(&mut self) {
+        self.entries.borrow_mut().map.remove(&self.key);
+        unsafe { release(self.ptr) };
+    }
+}
+
+/// 스레드 안전 레지스트리. 모든 접근이 하나의 `Mutex`를 거친다.
+#[derive(Debug)]
+pub struct SyncRegistry<K, T> {
+    entries: Arc<Mutex<Entries<K, T>>>,
+}
+
+/// [`SyncRegistry::register`]가 돌려주는 가드.
+#[derive(Debug)]
+pub struct SyncRegistration<K: Ord, T> {
+    key: (K, u64),
+    ptr: NonNull<T>,
+    entries: Arc<Mutex<Entries<K, T>>>,
+}
+
+// SAFETY: `NonNull<T>`는 항상 `Mutex`를 잡은 상태에서만 역참조한다. `Mutex<T>`와 같은 조건.
+unsafe impl<K: Send, T: Send> Send for Entries<K, T> {}
+unsafe impl<K: Ord + Send, T: Send> Send for SyncRegistration<K, T> {}
+unsafe impl<K: Ord + Send, T: Send> Sync for SyncRegistration<K, T> {}
+
+/// 값을 들고 있는 쪽이 panic하더라도 레지스트리 자체는 일관된 상태이므로 poison은 무시한다.
+fn lock<K, T>(entries: &Mutex<Entries<K, T>>) -> MutexGuard<'_, Entries<K, T>> {
+    entries.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+impl<K: Ord + Clone, T> SyncRegistry<K, T> {
+    pub fn new() -> Self {
+        Self {
+            entries: Arc::new(Mutex::new(Entries::new())),
+        }
+    }
+
+    pub fn register(&self, key: K, value: Pin<Box<T>>) -> SyncRegistration<K, T> {
+        let ptr = into_raw(value);
+        let id = lock(&self.entries).insert(key.clone(), ptr);
+        SyncRegistration {
+            key: (key, id),
+            ptr,
+            entries: Arc::clone(&self.entries),
+        }
+    }
+
+    /// # Deadlock
+    /// 콜백 안에서 같은 레지스트리나 그 가드에 접근하면 교착 상태에 빠진다.
+    pub fn for_each_mut<F>(&self, f: F)
+    where
+        F: FnMut(&K, Pin<&mut T>),
+    {
+        lock(&self.entries).for_each_mut(f);
+    }
+
+    pub fn len(&self) -> usize {
+        lock(&self.entries).map.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn keys(&self) -> Vec<K> {
+        lock(&self.entries)
+            .map
+            .keys()
+            .map(|(key, _)| key.clone())
+            .collect()
+    }
+}
+
+impl<K: Ord + Clone, T> Default for SyncRegistry<K, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, T> Clone for SyncRegistry<K, T> {
+    fn clone(&self) -> Self {
+        Self {
+            entries: Arc::clone(&self.entries),
+        }
+    }
+}
+
+impl<K: Ord, T> SyncRegistration<K, T> {
+    pub fn key(&self) -> &K {
+        &self.key.0
+    }
+
+    pub fn with<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+        let _guard = lock(&self.entries);
+        f(unsafe { self.ptr.as_ref() })
+    }
+
+    pub fn with_mut<R>(&self, f: impl FnOnce(Pin<&mut T>) -> R) -> R {
+        let _guard = lock(&self.entries);
+        f(unsafe { Pin::new_unchecked(&mut *self.ptr.as_ptr()) })
+    }
+}
+
+impl<K: Ord, T> Drop for SyncRegistration<K, T> {
+    fn drop(&mut self) {
+        lock(&self.entries).map.remove(&self.key);
+        unsafe { release(self.ptr) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{marker::PhantomPinned, thread};
+
+    use super::*;
+
+    /// step_005의 `Me`와 같은 역할. 이제 holder를 직접 알 필요가 없다.
+    #[derive(Debug)]
+    struct Me {
+        name: String,
+        mutate_by_holder: i32,
+        _pinned: PhantomPinned,
+    }
+
+    impl Me {
+        fn new(name: impl Into<String>) -> Pin<Box<Self>> {
+            Box::pin(Self {
+                name: name.into(),
+                mutate_by_holder: 0,
+                _pinned: PhantomPinned,
+            })
+        }
+
+        fn mutate_me(self: Pin<&mut Self>, val: i32) {
+            let this = unsafe { self.get_unchecked_mut() };
+            this.mutate_by_holder += val;
+        }
+    }
+
+    #[test]
+    fn run() {
+        let holder = Registry::new();
+
+        let a = holder.register("a", Me::new("a"));
+        let b = holder.register("b", Me::new("b"));
+        let c = holder.register("b", Me::new("b")); // duplicated key is kept!
+        assert_eq!(3, holder.len());
+
+        holder.for_each_mut(|_, me| me.mutate_me(1));
+        assert_eq!(1, a.borrow().mutate_by_holder);
+        assert_eq!(1, b.borrow().mutate_by_holder);
+        assert_eq!(1, c.borrow().mutate_by_holder);
+
+        c.with_mut(|me| me.mutate_me(10));
+        assert_eq!(11, c.borrow().mutate_by_holder);
+        assert_eq!("b", c.borrow().name);
+        assert_eq!(&"b", c.key());
+
+        // dropping a guard unregisters exactly that entry
+        drop(b);
+        assert_eq!(vec!["a", "b"], holder.keys());
+        holder.for_each_mut(|_, me| me.mutate_me(1));
+        assert_eq!(2, a.borrow().mutate_by_holder);
+        assert_eq!(12, c.borrow().mutate_by_holder);
+
+        drop(a);
+        drop(c);
+        assert!(holder.is_empty());
+    }
+
+    #[test]
+    fn broadcast_in_key_order() {
+        let holder = Registry::new();
+        let _guards: Vec<_> = [(3, "x"), (1, "y"), (2, "z"), (1, "w")]
+            .into_iter()
+            .map(|(key, name)| holder.register(key, Me::new(name)))
+            .collect();
+
+        let mut visited = vec![];
+        holder.for_each_mut(|key, me| visited.push((*key, me.name.clone())));
+        assert_eq!(
+            vec![
+                (1, "y".to_owned()),
+                (1, "w".to_owned()),
+                (2, "z".to_owned()),
+                (3, "x".to_owned())
+            ],
+            visited
+        );
+    }
+
+    #[test]
+    fn guard_outlives_registry_handle() {
+        let guard = {
+            let holder = Registry::new();
+            holder.register(0, Me::new("orphan"))
+        };
+        assert_eq!("orphan", guard.borrow().name);
+    }
+
+    #[test]
+    #[should_panic(expected = "already mutably borrowed")]
+    fn borrow_during_broadcast_panics() {
+        let holder = Registry::new();
+        let a = holder.register("a", Me::new("a"));
+        holder.for_each_mut(|_, _| {
+            let _ = a.borrow();
+        });
+    }
+
+    #[test]
+    fn sync_registry_across_threads() {
+        let holder = SyncRegistry::new();
+        let guards: Vec<_> = (0..4)
+            .map(|i| holder.register(i, Me::new(i.to_string())))
+            .collect();
+
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let holder = holder.clone();
+                thread::spawn(move || holder.for_each_mut(|_, me| me.mutate_me(1)))
+            })
+            .collect();
+        handles.into_iter().for_each(|h| h.join().unwrap());
+
+        guards
+            .iter()
+            .for_each(|guard| assert_eq!(8, guard.with(|me| me.mutate_by_holder)));
+
+        // guards can be dropped on another thread as well
+        thread::spawn(move || drop(guards)).join().unwrap();
+        assert!(holder.is_empty());
+    }
+}
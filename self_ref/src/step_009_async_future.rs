@@ -0,0 +1,302 @@
+//! 지금까지 `Pin`/`PhantomPinned`를 동기 코드(`Test`, `Me`)로만 실험했지만, 사실 `Pin`이
+//! 만들어진 진짜 이유는 async다. 컴파일러는 `async fn`을 상태 기계(state machine)로
+//! 바꾸는데, `.await` 지점을 넘어 살아있는 지역 변수와 그 변수를 빌린 참조가 같은
+//! 상태 안에 함께 저장되므로 결과물은 self-referential struct가 된다.
+//!
+//! 여기서는 다음 `async fn`을 손으로 풀어 쓴 [`CountWords`]를 만든다.
+//!
+//! ```ignore
+//! async fn count_words(input: &str) -> usize {
+//!     let buf: [u8; CAPACITY] = copy_of(input); // 상태 안에 저장되는 지역 변수
+//!     let mut rest: &[u8] = &buf[..];           // 그 변수를 빌린 참조
+//!     let mut count = 0;
+//!     while let Some((_word, after)) = next_word(rest) {
+//!         count += 1;
+//!         rest = after;
+//!         yield_now().await; // 여기서 멈췄다가 다시 poll된다
+//!     }
+//!     count
+//! }
+//! ```
+//!
+//! 그리고 이 future를 굴리기 위해 `RawWaker`로 waker를 직접 만드는 [`block_on`]을 둔다.
+//!
+//! source: <https://rust-lang.github.io/async-book/04_pinning/01_chapter.html>
+
+use std::{
+    future::Future,
+    marker::PhantomPinned,
+    pin::{pin, Pin},
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc,
+    },
+    task::{Context, Poll, RawWaker, RawWakerVTable, Waker},
+    thread::{self, Thread},
+};
+
+pub const CAPACITY: usize = 64;
+
+/// 컴파일러가 만드는 상태 기계와 같은 모양.
+#[derive(Debug)]
+enum State {
+    /// 아직 한 번도 poll되지 않았다. 이때까지는 move해도 괜찮다.
+    Unresumed,
+    /// `.await`에서 멈춰 있다. `rest`는 같은 구조체의 `buf`를 가리킨다.
+    Suspended {
+        rest: *const [u8],
+    },
+    Returned,
+}
+
+/// 입력을 상태 안의 버퍼에 복사한 뒤, poll될 때마다 단어를 하나씩 센다.
+#[derive(Debug)]
+pub struct CountWords {
+    buf: [u8; CAPACITY],
+    len: usize,
+    count: usize,
+    state: State,
+    _pinned: PhantomPinned,
+}
+
+impl CountWords {
+    /// # Panics
+    /// `input`이 [`CAPACITY`] 바이트보다 길면 panic한다.
+    pub fn new(input: &str) -> Self {
+        assert!(
+            input.len() <= CAPACITY,
+            "input is longer than {CAPACITY} bytes"
+        );
+        let mut buf = [0; CAPACITY];
+        buf[..input.len()].copy_from_slice(input.as_bytes());
+        Self {
+            buf,
+            len: input.len(),
+            count: 0,
+            state: State::Unresumed,
+            _pinned: PhantomPinned,
+        }
+    }
+
+    /// 지금까지 센 단어 수
+    pub fn count(&self) -> usize {
+        self.count
+    }
+}
+
+/// `rest`에서 공백을 건너뛰고 단어 하나와 그 뒤의 나머지를 잘라낸다.
+fn next_word(rest: &[u8]) -> Option<(&[u8], &[u8])> {
+    let start = rest.iter().position(|b| !b.is_ascii_whitespace())?;
+    let rest = &rest[start..];
+    let end = rest
+        .iter()
+        .position(|b| b.is_ascii_whitespace())
+        .unwrap_or(rest.len());
+    Some(rest.split_at(end))
+}
+
+impl Future for CountWords {
+    type Output = usize;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // `pin_project!`의 `project()`는 모든 필드에 대해 `&mut`를 새로 만들기 때문에,
+        // 여기서 쓰면 `buf`에 대한 `&mut`가 지난번에 빌려 둔 `rest`를 무효화해 버린다.
+        // 그래서 `!Unpin`인 구조체 전체에 대한 `&mut`만 얻은 뒤 필드에 직접 접근한다.
+        // SAFETY: 어떤 필드도 move하지 않는다.
+        let this = unsafe { self.get_unchecked_mut() };
+        let rest = match this.state {
+            // 처음 poll될 때 비로소 자기 자신을 가리키는 참조를 만든다. 이미 pin된
+            // 상태이므로 이 참조는 future가 drop될 때까지 유효하다.
+            State::Unresumed => &this.buf[..this.len],
+            State::Suspended { rest } => unsafe { &*rest },
+            State::Returned => panic!("`CountWords` polled after completion"),
+        };
+
+        match next_word(rest) {
+            Some((_word, after)) => {
+                this.count += 1;
+                this.state = State::Suspended { rest: after };
+                // yield_now().await: 바로 다시 poll해 달라고 알린 뒤 양보한다.
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+            None => {
+                this.state = State::Returned;
+                Poll::Ready(this.count)
+            }
+        }
+    }
+}
+
+/// waker가 가리키는 실제 데이터. 깨워지면 executor 스레드를 unpark한다.
+#[derive(Debug)]
+pub struct Signal {
+    woken: AtomicBool,
+    wake_count: AtomicUsize,
+    thread: Thread,
+}
+
+impl Signal {
+    /// 현재 스레드를 깨우는 신호를 만든다.
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self {
+            woken: AtomicBool::new(false),
+            wake_count: AtomicUsize::new(0),
+            thread: thread::current(),
+        })
+    }
+
+    pub fn wake_count(&self) -> usize {
+        self.wake_count.load(Ordering::SeqCst)
+    }
+
+    fn wake(&self) {
+        self.wake_count.fetch_add(1, Ordering::SeqCst);
+        self.woken.store(true, Ordering::SeqCst);
+        self.thread.unpark();
+    }
+
+    /// 깨워질 때까지 현재 스레드를 재운다.
+    fn wait(&self) {
+        while !self.woken.swap(false, Ordering::SeqCst) {
+            thread::park();
+        }
+    }
+}
+
+/// `Waker`는 `Arc<Signal>`을 `RawWaker`의 data pointer로 들고 다닌다.
+/// clone/drop은 `Arc`의 참조 카운트를 그대로 올리고 내린다.
+const VTABLE: RawWakerVTable = RawWakerVTable::new(clone_waker, wake, wake_by_ref, drop_waker);
+
+unsafe fn clone_waker(data: *const ()) -> RawWaker {
+    Arc::increment_strong_count(data as *const Signal);
+    RawWaker::new(data, &VTABLE)
+}
+
+unsafe fn wake(data: *const ()) {
+    let signal = Arc::from_raw(data as *const Signal);
+    signal.wake();
+}
+
+unsafe fn wake_by_ref(data: *const ()) {
+    (*(data as *const Signal)).wake();
+}
+
+unsafe fn drop_waker(data: *const ()) {
+    drop(Arc::from_raw(data as *const Signal));
+}
+
+pub fn waker(signal: Arc<Signal>) -> Waker {
+    let raw = RawWaker::new(Arc::into_raw(signal) as *const (), &VTABLE);
+    // SAFETY: VTABLE의 함수들은 모두 `Arc<Signal>`의 계약을 지킨다.
+    unsafe { Waker::from_raw(raw) }
+}
+
+/// 가장 단순한 로컬 executor. future를 스택에 pin한 뒤, 깨워질 때마다 다시 poll한다.
+pub fn block_on<F: Future>(fut: F) -> F::Output {
+    let signal = Signal::new();
+    let waker = waker(Arc::clone(&signal));
+    let mut cx = Context::from_waker(&waker);
+    let mut fut = pin!(fut);
+    loop {
+        if let Poll::Ready(output) = fut.as_mut().poll(&mut cx) {
+            return output;
+        }
+        signal.wait();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::marker::PhantomData;
+
+    use super::*;
+
+    /// 음수 트레이트 바운드를 쓸 수 없으니 autoref 우선순위로 `Unpin` 여부를 알아낸다.
+    /// `T: Unpin`이면 고유 메서드가, 아니면 트레이트의 기본 메서드가 불린다.
+    struct Probe<T>(PhantomData<T>);
+    impl<T: Unpin> Probe<T> {
+        fn is_unpin(&self) -> bool {
+            true
+        }
+    }
+    trait NotUnpin {
+        fn is_unpin(&self) -> bool {
+            false
+        }
+    }
+    impl<T> NotUnpin for Probe<T> {}
+
+    #[test]
+    fn is_not_unpin() {
+        assert!(!Probe::<CountWords>(PhantomData).is_unpin());
+        assert!(Probe::<String>(PhantomData).is_unpin());
+    }
+
+    #[test]
+    fn poll_manually() {
+        let signal = Signal::new();
+        let waker = waker(Arc::clone(&signal));
+        let mut cx = Context::from_waker(&waker);
+
+        // 아직 poll하기 전이므로 move해도 괜찮다.
+        let fut = CountWords::new("  self   referential future ");
+        let moved = fut;
+        let mut fut = Box::pin(moved);
+
+        for expected in 1..=3 {
+            assert_eq!(Poll::Pending, fut.as_mut().poll(&mut cx));
+            assert_eq!(expected, fut.count());
+            assert_eq!(expected, signal.wake_count());
+        }
+        assert_eq!(Poll::Ready(3), fut.as_mut().poll(&mut cx));
+        assert_eq!(3, signal.wake_count());
+    }
+
+    #[test]
+    #[should_panic(expected = "polled after completion")]
+    fn poll_after_completion() {
+        let waker = waker(Signal::new());
+        let mut cx = Context::from_waker(&waker);
+        let mut fut = Box::pin(CountWords::new(""));
+        assert_eq!(Poll::Ready(0), fut.as_mut().poll(&mut cx));
+        let _ = fut.as_mut().poll(&mut cx);
+    }
+
+    #[test]
+    fn block_on_count_words() {
+        assert_eq!(0, block_on(CountWords::new("")));
+        assert_eq!(0, block_on(CountWords::new(" \t\n ")));
+        assert_eq!(4, block_on(CountWords::new("a bb\tccc\ndddd")));
+
+        // 컴파일러가 만든 future 안에 손으로 만든 future가 들어가도 잘 동작한다.
+        let total = block_on(async {
+            let a = CountWords::new("one two").await;
+            let b = CountWords::new("three").await;
+            a + b
+        });
+        assert_eq!(3, total);
+    }
+
+    #[test]
+    fn waker_clone_and_wake_from_other_thread() {
+        let signal = Signal::new();
+        let waker = waker(Arc::clone(&signal));
+        let cloned = waker.clone();
+        assert_eq!(3, Arc::strong_count(&signal));
+
+        thread::spawn(move || cloned.wake()).join().unwrap();
+        signal.wait(); // already woken, returns immediately
+        assert_eq!(1, signal.wake_count());
+        assert_eq!(2, Arc::strong_count(&signal));
+
+        drop(waker);
+        assert_eq!(1, Arc::strong_count(&signal));
+    }
+
+    #[test]
+    #[should_panic(expected = "longer than")]
+    fn input_too_long() {
+        let _fut = CountWords::new(&"a".repeat(CAPACITY + 1));
+    }
+}
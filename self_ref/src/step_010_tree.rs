@@ -0,0 +1,300 @@
+//! [`step_005_self_ref_holder`](crate::step_005_self_ref_holder) / [`step_006_self_ref_cmp`](crate::step_006_self_ref_cmp) 에서는 `Me` → `Holder`로
+//! 한 단계짜리 역참조만 있었다. 이번에는 여러 단계로 이어지는 트리를 만든다.
+//!
+//! - 부모는 자식들을 순서대로 `Pin<Box<TreeNode<T>>>`로 소유한다.
+//! - 자식은 부모를 raw pointer로 가리킨다. 부모도 고정(pin)되어 있으므로 이 포인터는
+//!   자식이 부모에 매달려 있는 동안 항상 유효하다.
+//! - `Rc`/`Weak`를 쓰지 않으니 순환 참조도, 참조 카운트도 없다. 소유 관계는 오직
+//!   부모 → 자식 방향이고, 빌림 검사기가 트리 전체의 aliasing을 지켜준다.
+//! - 자식을 `detach`하면 부모 포인터가 끊기고 독립된 루트가 된다. 노드가 drop될 때는
+//!   자식들의 부모 포인터를 먼저 끊은 뒤 (깊은 트리에서도 스택이 넘치지 않도록) 반복문으로
+//!   하위 트리를 해제한다.
+
+use std::{collections::VecDeque, fmt::Debug, marker::PhantomPinned, pin::Pin, ptr::NonNull};
+
+pub struct TreeNode<T> {
+    val: T,
+    parent: Option<NonNull<TreeNode<T>>>,
+    children: Vec<Pin<Box<TreeNode<T>>>>,
+    _pinned: PhantomPinned,
+}
+
+/// depth-first (pre-order) traversal
+pub struct Dfs<'a, T> {
+    stack: Vec<&'a TreeNode<T>>,
+}
+
+/// breadth-first (level-order) traversal
+pub struct Bfs<'a, T> {
+    queue: VecDeque<&'a TreeNode<T>>,
+}
+
+/// 부모 포인터를 따라 루트까지 올라가는 iterator. 자기 자신은 포함하지 않는다.
+pub struct Ancestors<'a, T> {
+    cur: Option<&'a TreeNode<T>>,
+}
+
+impl<T> TreeNode<T> {
+    /// 부모가 없는 새 루트 노드
+    pub fn new(val: T) -> Pin<Box<Self>> {
+        Box::pin(Self {
+            val,
+            parent: None,
+            children: vec![],
+            _pinned: PhantomPinned,
+        })
+    }
+
+    pub fn value(&self) -> &T {
+        &self.val
+    }
+
+    /// `val`은 구조적으로 고정된 필드가 아니므로 `&mut`로 꺼내도 된다.
+    pub fn value_mut(self: Pin<&mut Self>) -> &mut T {
+        unsafe { &mut self.get_unchecked_mut().val }
+    }
+
+    pub fn parent(&self) -> Option<&Self> {
+        // SAFETY: 부모는 자식을 소유하고 있으므로 `self`가 살아있는 동안 부모도 살아있다.
+        self.parent.map(|parent| unsafe { &*parent.as_ptr() })
+    }
+
+    pub fn is_root(&self) -> bool {
+        self.parent.is_none()
+    }
+
+    pub fn root(&self) -> &Self {
+        self.ancestors().last().unwrap_or(self)
+    }
+
+    pub fn depth(&self) -> usize {
+        self.ancestors().count()
+    }
+
+    pub fn ancestors(&self) -> Ancestors<'_, T> {
+        Ancestors { cur: self.parent() }
+    }
+
+    pub fn children(&self) -> impl DoubleEndedIterator<Item = &Self> {
+        self.children.iter().map(|child| child.as_ref().get_ref())
+    }
+
+    pub fn child(&self, index: usize) -> Option<&Self> {
+        self.children
+            .get(index)
+            .map(|child| child.as_ref().get_ref())
+    }
+
+    pub fn child_mut(self: Pin<&mut Self>, index: usize) -> Option<Pin<&mut Self>> {
+        let this = unsafe { self.get_unchecked_mut() };
+        this.children.get_mut(index).map(|child| child.as_mut())
+    }
+
+    pub fn len_children(&self) -> usize {
+        self.children.len()
+    }
+
+    /// 루트 노드(다른 트리에 속하지 않은 하위 트리)를 마지막 자식으로 붙인다.
+    /// 붙인 자식을 돌려준다.
+    pub fn add_child(self: Pin<&mut Self>, mut child: Pin<Box<Self>>) -> Pin<&mut Self> {
+        debug_assert!(child.is_root());
+        let this = unsafe { self.get_unchecked_mut() };
+        // `&*this`에서 만든 포인터는 뒤이은 `this.children` 수정에 의해 무효화되므로
+        // 쓰기 권한이 있는 `&mut`로부터 만든다.
+        let parent = NonNull::from(&mut *this);
+        unsafe { child.as_mut().get_unchecked_mut() }.parent = Some(parent);
+        this.children.push(child);
+        this.children.last_mut().unwrap().as_mut()
+    }
+
+    /// `TreeNode::new(val)`을 만들어 바로 붙인다.
+    pub fn push_child(self: Pin<&mut Self>, val: T) -> Pin<&mut Self> {
+        self.add_child(Self::new(val))
+    }
+
+    /// `index`번째 자식을 떼어낸다. 떼어낸 자식은 부모 포인터가 끊긴 루트가 된다.
+    pub fn detach(self: Pin<&mut Self>, index: usize) -> Option<Pin<Box<Self>>> {
+        let this = unsafe { self.get_unchecked_mut() };
+        if index >= this.children.len() {
+            return None;
+        }
+        let mut child = this.children.remove(index);
+        unsafe { child.as_mut().get_unchecked_mut() }.parent = None;
+        Some(child)
+    }
+
+    pub fn dfs(&self) -> Dfs<'_, T> {
+        Dfs { stack: vec![self] }
+    }
+
+    pub fn bfs(&self) -> Bfs<'_, T> {
+        Bfs {
+            queue: VecDeque::from([self]),
+        }
+    }
+}
+
+impl<T> Drop for TreeNode<T> {
+    fn drop(&mut self) {
+        // 재귀적으로 drop하면 깊은 트리에서 스택이 넘칠 수 있으니, 손자들을 모두 꺼내
+        // 평평하게 만든 뒤 하나씩 해제한다.
+        let mut pending = std::mem::take(&mut self.children);
+        while let Some(mut child) = pending.pop() {
+            let child = unsafe { child.as_mut().get_unchecked_mut() };
+            child.parent = None;
+            pending.append(&mut child.children);
+        }
+    }
+}
+
+impl<T: Debug> Debug for TreeNode<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TreeNode")
+            .field("val", &self.val)
+            .field("children", &self.children)
+            .finish()
+    }
+}
+
+impl<'a, T> Iterator for Dfs<'a, T> {
+    type Item = &'a TreeNode<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        self.stack.extend(node.children().rev());
+        Some(node)
+    }
+}
+
+impl<'a, T> Iterator for Bfs<'a, T> {
+    type Item = &'a TreeNode<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.queue.pop_front()?;
+        self.queue.extend(node.children());
+        Some(node)
+    }
+}
+
+impl<'a, T> Iterator for Ancestors<'a, T> {
+    type Item = &'a TreeNode<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.cur?;
+        self.cur = node.parent();
+        Some(node)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    ///        root
+    ///       /    \
+    ///      a      b
+    ///     / \     |
+    ///    a1  a2   b1
+    fn sample() -> Pin<Box<TreeNode<&'static str>>> {
+        let mut root = TreeNode::new("root");
+        let mut a = root.as_mut().push_child("a");
+        a.as_mut().push_child("a1");
+        a.as_mut().push_child("a2");
+        let mut b = TreeNode::new("b");
+        b.as_mut().push_child("b1");
+        root.as_mut().add_child(b);
+        root
+    }
+
+    fn values<'a>(iter: impl Iterator<Item = &'a TreeNode<&'static str>>) -> Vec<&'static str> {
+        iter.map(|node| *node.value()).collect()
+    }
+
+    #[test]
+    fn traversal() {
+        let root = sample();
+        assert_eq!(vec!["root", "a", "a1", "a2", "b", "b1"], values(root.dfs()));
+        assert_eq!(vec!["root", "a", "b", "a1", "a2", "b1"], values(root.bfs()));
+        assert_eq!(vec!["a", "b"], values(root.children()));
+    }
+
+    #[test]
+    fn parent_back_pointers() {
+        let root = sample();
+        let a2 = root.child(0).unwrap().child(1).unwrap();
+        assert_eq!("a2", *a2.value());
+        assert_eq!(Some(&"a"), a2.parent().map(|p| p.value()));
+        assert_eq!(vec!["a", "root"], values(a2.ancestors()));
+        assert_eq!(2, a2.depth());
+        assert_eq!("root", *a2.root().value());
+        assert!(std::ptr::eq(root.as_ref().get_ref(), a2.root()));
+
+        // every child points back to the node that owns it
+        root.dfs().for_each(|node| {
+            node.children()
+                .for_each(|child| assert!(std::ptr::eq(node, child.parent().unwrap())));
+        });
+    }
+
+    #[test]
+    fn moving_the_root_box_keeps_links() {
+        let root = sample();
+        let mut roots = vec![root];
+        roots.reserve(100); // move the `Pin<Box<_>>`, not the node itself
+        let root = roots.pop().unwrap();
+        let b1 = root.child(1).unwrap().child(0).unwrap();
+        assert_eq!(Some(&"b"), b1.parent().map(|p| p.value()));
+    }
+
+    #[test]
+    fn mutate_through_child_mut() {
+        let mut root = sample();
+        let mut a = root.as_mut().child_mut(0).unwrap();
+        *a.as_mut().value_mut() = "A";
+        let a1 = a.as_mut().child_mut(0).unwrap();
+        assert_eq!(
+            Some(&"A"),
+            a1.as_ref().get_ref().parent().map(|p| p.value())
+        );
+        assert!(root.as_mut().child_mut(2).is_none());
+        assert_eq!(vec!["root", "A", "a1", "a2", "b", "b1"], values(root.dfs()));
+    }
+
+    #[test]
+    fn detach_and_reattach() {
+        let mut root = sample();
+        let mut a = root.as_mut().detach(0).unwrap();
+        assert!(a.is_root());
+        assert_eq!(0, a.depth());
+        assert_eq!(vec!["root", "b", "b1"], values(root.dfs()));
+        assert!(root.as_mut().detach(5).is_none());
+
+        // grandchildren still point at `a`, which hasn't moved
+        assert!(a
+            .children()
+            .all(|child| std::ptr::eq(child.parent().unwrap(), &*a)));
+
+        // reattach `a` under `b1`
+        a.as_mut().push_child("a3");
+        let mut b = root.as_mut().child_mut(0).unwrap();
+        let b1 = b.as_mut().child_mut(0).unwrap();
+        let a = b1.add_child(a);
+        assert_eq!(3, a.depth());
+        assert_eq!(
+            vec!["root", "b", "b1", "a", "a1", "a2", "a3"],
+            values(root.dfs())
+        );
+    }
+
+    #[test]
+    fn drop_deep_tree() {
+        // 재귀적으로 drop했다면 스택이 넘쳤을 깊이
+        let mut root = TreeNode::new(0);
+        let mut cur = root.as_mut();
+        for i in 1..100_000 {
+            cur = cur.push_child(i);
+        }
+        assert_eq!(99_999, cur.depth());
+        drop(root);
+    }
+}
@@ -0,0 +1,6 @@
+/// `!Unpin` 구조체를 `Pin` 밖으로 꺼내 move하려는 코드는 컴파일조차 되지 않아야 한다.
+#[test]
+fn compile_fail() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/compile_fail/*.rs");
+}
@@ -0,0 +1,31 @@
+use std::marker::PhantomPinned;
+
+use self_ref::pin_project;
+
+pin_project! {
+    struct Pair {
+        value: String,
+        #[pin]
+        pinned: PhantomPinned,
+    }
+    project = PairProj;
+}
+
+fn main() {
+    let mut pair1 = Box::pin(Pair {
+        value: "pair1".to_owned(),
+        pinned: PhantomPinned,
+    });
+    let mut pair2 = Box::pin(Pair {
+        value: "pair2".to_owned(),
+        pinned: PhantomPinned,
+    });
+    let a = pair1.as_mut().project();
+    let b = pair2.as_mut().project();
+
+    // 고정되지 않은 필드는 `&mut`로 나오므로 swap해도 된다.
+    std::mem::swap(a.value, b.value);
+
+    // `#[pin]` 필드는 `Pin<&mut _>`로만 projection되므로 swap할 수 없다.
+    std::mem::swap(a.pinned.get_mut(), b.pinned.get_mut());
+}
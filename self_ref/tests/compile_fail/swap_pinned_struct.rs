@@ -0,0 +1,9 @@
+use self_ref::PinnedTest as Test;
+
+fn main() {
+    let mut test1 = Test::new("test1");
+    let mut test2 = Test::new("test2");
+
+    // `Test`는 `PhantomPinned` 때문에 `!Unpin`이므로 `get_mut`으로 꺼낼 수 없다.
+    std::mem::swap(test1.as_mut().get_mut(), test2.as_mut().get_mut());
+}